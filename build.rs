@@ -0,0 +1,130 @@
+//! Generates `GENERATED_CALL_FLAG_TABLE` and `GENERATED_GL_ENUM_NAMES` from
+//! the Khronos `gl.xml` registry at `registry/gl.xml`. `src/trace.rs`
+//! `include!`s the result and consults it as a supplementary lookup —
+//! `CALL_FLAG_TABLE` stays the hand-maintained source of truth, but a new
+//! command added to the registry gets *some* flag classification (and a new
+//! enum gets a printable name in `dump::format_enum`'s fallback) without
+//! waiting on a hand edit there.
+//!
+//! Always writes `$OUT_DIR/gl_registry.rs` so `include!` has something to
+//! include even when the registry is missing or fails to parse (empty
+//! tables in that case) — there's no scenario where the generated file
+//! doesn't exist but something still tries to include it.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    let registry_path = Path::new("registry/gl.xml");
+    println!("cargo:rerun-if-changed={}", registry_path.display());
+
+    let (flags, enums) = match fs::read_to_string(registry_path) {
+        Ok(xml) => {
+            let mut flags: Vec<(String, u16)> =
+                parse_commands(&xml).into_iter().filter_map(|name| classify_command(&name).map(|flag| (name, flag))).collect();
+            flags.sort_by(|a, b| a.0.cmp(&b.0));
+            flags.dedup_by(|a, b| a.0 == b.0);
+
+            // `Call::lookup_call_flag`'s generated-table lookup is a linear
+            // HashMap built from this slice, so sort order isn't load-bearing
+            // there the way it is for `CALL_FLAG_TABLE`'s old binary search —
+            // kept anyway so a diff of the generated file stays readable.
+            assert!(flags.windows(2).all(|pair| pair[0].0 <= pair[1].0), "generated CALL_FLAG_TABLE is not sorted by name");
+
+            (flags, parse_enums(&xml))
+        }
+        Err(_) => (Vec::new(), Vec::new()),
+    };
+
+    let mut out = String::new();
+    out.push_str("pub static GENERATED_CALL_FLAG_TABLE: &[(&str, u16)] = &[\n");
+    for (name, flag) in &flags {
+        out.push_str(&format!("    ({:?}, {}),\n", name, flag));
+    }
+    out.push_str("];\n\n");
+
+    out.push_str("pub static GENERATED_GL_ENUM_NAMES: &[(&str, i64)] = &[\n");
+    for (name, value) in &enums {
+        out.push_str(&format!("    ({:?}, {}),\n", name, value));
+    }
+    out.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let dest = Path::new(&out_dir).join("gl_registry.rs");
+    fs::write(&dest, out).expect("failed to write generated gl registry");
+}
+
+/// Pulls every `<command><proto>...<name>NAME</name></proto>` out of `gl.xml`.
+fn parse_commands(xml: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut rest = xml;
+    while let Some(start) = rest.find("<proto") {
+        rest = &rest[start..];
+        if let Some(name) = extract_tag(rest, "name") {
+            names.push(name);
+        }
+        rest = &rest[1..];
+    }
+    names
+}
+
+/// Pulls every `<enum name="..." value="..."/>` out of `gl.xml`'s `<enums>` groups.
+fn parse_enums(xml: &str) -> Vec<(String, i64)> {
+    let mut enums = Vec::new();
+    for line in xml.lines() {
+        let trimmed = line.trim();
+        if !trimmed.starts_with("<enum ") {
+            continue;
+        }
+        let (Some(name), Some(value)) = (extract_attr(trimmed, "name"), extract_attr(trimmed, "value")) else {
+            continue;
+        };
+        let parsed = match value.strip_prefix("0x") {
+            Some(hex) => i64::from_str_radix(hex, 16).ok(),
+            None => value.parse::<i64>().ok(),
+        };
+        if let Some(value) = parsed {
+            enums.push((name, value));
+        }
+    }
+    enums
+}
+
+fn extract_tag(xml: &str, tag: &str) -> Option<String> {
+    let open = format!("<{}>", tag);
+    let close = format!("</{}>", tag);
+    let start = xml.find(&open)? + open.len();
+    let end = xml[start..].find(&close)? + start;
+    Some(xml[start..end].to_string())
+}
+
+fn extract_attr(tag_src: &str, attr: &str) -> Option<String> {
+    let needle = format!("{}=\"", attr);
+    let start = tag_src.find(&needle)? + needle.len();
+    let end = tag_src[start..].find('"')? + start;
+    Some(tag_src[start..end].to_string())
+}
+
+/// The same heuristics `Call::lookup_call_flag` applies by hand: render
+/// calls draw/clear/dispatch/blit, side-effect-free calls only query state,
+/// and present calls end (and swap) the frame.
+fn classify_command(name: &str) -> Option<u16> {
+    const RENDER: u16 = 8;
+    const NO_SIDE_EFFECTS: u16 = 4;
+    const END_FRAME: u16 = 32;
+    const SWAPBUFFERS: u16 = 48;
+
+    if name.contains("Draw") || name.contains("Clear") || name.contains("Dispatch") || name.contains("Blit") {
+        return Some(RENDER);
+    }
+    if name.contains("SwapBuffers") || name.contains("Present") {
+        return Some(SWAPBUFFERS | END_FRAME);
+    }
+    if let Some(rest) = name.strip_prefix("gl") {
+        if rest.starts_with("Get") || rest.starts_with("Is") || rest.contains("Query") || rest.contains("Check") {
+            return Some(NO_SIDE_EFFECTS);
+        }
+    }
+    None
+}
@@ -0,0 +1,294 @@
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::call::Call;
+use crate::parser::{Parser, ParserError};
+use crate::signatures;
+use crate::trace;
+use crate::value_structure::{self, Value};
+
+/// Output format for [`dump_trace`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DumpFormat {
+    Text,
+    Json,
+}
+
+/// Renders a parsed `Call` the way `apitrace dump` does, e.g.
+/// `1234 glBindTexture(target = GL_TEXTURE_2D, texture = 7)`.
+pub struct CallDump<'a>(pub &'a Call);
+
+impl<'a> fmt::Display for CallDump<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let call = self.0;
+        write!(f, "{} {}(", call.number, call.sig.name)?;
+        for (index, arg) in call.args.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            let name = call.sig.arg_names.get(index).map(String::as_str).unwrap_or("?");
+            write!(f, "{} = {}", name, format_value(arg.as_ref()))?;
+        }
+        write!(f, ")")?;
+        if let Some(ret) = &call.ret {
+            write!(f, " = {}", format_value(ret.as_ref()))?;
+        }
+        for frame in &call.backtrace {
+            write!(
+                f,
+                "\n    at {} ({}:{} +{:#x}) [{}]",
+                frame.function, frame.filename, frame.linenumber, frame.offset, frame.module
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Streaming equivalent of the `Display` impl, for writing straight to a file or stdout.
+pub fn write_call<W: Write>(call: &Call, writer: &mut W) -> io::Result<()> {
+    writeln!(writer, "{}", CallDump(call))
+}
+
+/// Renders a call as a single JSON object: `{"number": .., "name": .., "args": {...}, "ret": ..}`.
+pub fn write_call_json<W: Write>(call: &Call, writer: &mut W) -> io::Result<()> {
+    write!(writer, "{{\"number\": {}, \"name\": {}, \"args\": {{", call.number, json_string(&call.sig.name))?;
+    for (index, arg) in call.args.iter().enumerate() {
+        if index > 0 {
+            write!(writer, ", ")?;
+        }
+        let name = call.sig.arg_names.get(index).map(String::as_str).unwrap_or("?");
+        write!(writer, "{}: {}", json_string(name), format_value_json(arg.as_ref()))?;
+    }
+    write!(writer, "}}")?;
+    if let Some(ret) = &call.ret {
+        write!(writer, ", \"ret\": {}", format_value_json(ret.as_ref()))?;
+    }
+    if !call.backtrace.is_empty() {
+        write!(writer, ", \"backtrace\": [")?;
+        for (index, frame) in call.backtrace.iter().enumerate() {
+            if index > 0 {
+                write!(writer, ", ")?;
+            }
+            write!(
+                writer,
+                "{{\"function\": {}, \"filename\": {}, \"linenumber\": {}, \"module\": {}}}",
+                json_string(&frame.function),
+                json_string(&frame.filename),
+                frame.linenumber,
+                json_string(&frame.module)
+            )?;
+        }
+        write!(writer, "]")?;
+    }
+    writeln!(writer, "}}")
+}
+
+/// Parses and dumps every call in `parser` to `writer` in the given format,
+/// independently of `Retracer` — this is the whole point of the dump
+/// subsystem, i.e. being able to inspect a trace without replaying it.
+pub fn dump_trace<W: Write>(parser: &mut Parser, writer: &mut W, format: DumpFormat) -> Result<(), ParserError> {
+    parser.parse_properties()?;
+    if format == DumpFormat::Json {
+        writeln!(writer, "[").ok();
+    }
+    let mut first = true;
+    for call in parser.calls() {
+        let call = call?;
+        match format {
+            DumpFormat::Text => {
+                write_call(&call, writer).ok();
+            }
+            DumpFormat::Json => {
+                if !first {
+                    writeln!(writer, ",").ok();
+                }
+                write_call_json(&call, writer).ok();
+                first = false;
+            }
+        }
+    }
+    if format == DumpFormat::Json {
+        writeln!(writer, "]").ok();
+    }
+    Ok(())
+}
+
+fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Renders a single value, resolving enums/bitmasks/structs to their symbolic form.
+pub fn format_value(value: &dyn Value) -> String {
+    let any = value.as_any();
+    if any.downcast_ref::<value_structure::None>().is_some() {
+        "NULL".to_string()
+    } else if let Some(v) = any.downcast_ref::<value_structure::Bool>() {
+        v.value.to_string()
+    } else if let Some(v) = any.downcast_ref::<value_structure::I32>() {
+        v.value.to_string()
+    } else if let Some(v) = any.downcast_ref::<value_structure::U32>() {
+        v.value.to_string()
+    } else if let Some(v) = any.downcast_ref::<value_structure::Float>() {
+        v.value.to_string()
+    } else if let Some(v) = any.downcast_ref::<value_structure::Double>() {
+        v.value.to_string()
+    } else if let Some(v) = any.downcast_ref::<value_structure::VString>() {
+        format!("{:?}", v.value)
+    } else if let Some(v) = any.downcast_ref::<value_structure::WString>() {
+        format!("{:?}", v.value)
+    } else if let Some(v) = any.downcast_ref::<value_structure::Repr>() {
+        format!("{} /* {} */", format_value(v.human.as_ref()), format_value(v.machine.as_ref()))
+    } else if let Some(v) = any.downcast_ref::<value_structure::Enum>() {
+        format_enum(v)
+    } else if let Some(v) = any.downcast_ref::<value_structure::Bitmask>() {
+        format_bitmask(v)
+    } else if let Some(v) = any.downcast_ref::<value_structure::Struct>() {
+        format_struct(v)
+    } else if let Some(v) = any.downcast_ref::<value_structure::Array>() {
+        format_array(v)
+    } else if let Some(v) = any.downcast_ref::<value_structure::Blob>() {
+        format!("blob({} bytes)", v.size)
+    } else if let Some(v) = any.downcast_ref::<value_structure::Pointer>() {
+        format!("{:#x}", v.value as usize)
+    } else {
+        "<unknown>".to_string()
+    }
+}
+
+/// JSON equivalent of [`format_value`]: aggregates become proper JSON
+/// containers (objects/arrays) instead of their apitrace-style text form,
+/// scalars stay as JSON numbers/strings/literals.
+pub fn format_value_json(value: &dyn Value) -> String {
+    let any = value.as_any();
+    if any.downcast_ref::<value_structure::None>().is_some() {
+        "null".to_string()
+    } else if let Some(v) = any.downcast_ref::<value_structure::Bool>() {
+        v.value.to_string()
+    } else if let Some(v) = any.downcast_ref::<value_structure::I32>() {
+        v.value.to_string()
+    } else if let Some(v) = any.downcast_ref::<value_structure::U32>() {
+        v.value.to_string()
+    } else if let Some(v) = any.downcast_ref::<value_structure::Float>() {
+        v.value.to_string()
+    } else if let Some(v) = any.downcast_ref::<value_structure::Double>() {
+        v.value.to_string()
+    } else if let Some(v) = any.downcast_ref::<value_structure::VString>() {
+        json_string(&v.value)
+    } else if let Some(v) = any.downcast_ref::<value_structure::WString>() {
+        json_string(&v.value)
+    } else if let Some(v) = any.downcast_ref::<value_structure::Repr>() {
+        format_value_json(v.human.as_ref())
+    } else if let Some(v) = any.downcast_ref::<value_structure::Enum>() {
+        json_string(&format_enum(v))
+    } else if let Some(v) = any.downcast_ref::<value_structure::Bitmask>() {
+        json_string(&format_bitmask(v))
+    } else if let Some(v) = any.downcast_ref::<value_structure::Struct>() {
+        let mut out = String::from("{");
+        for (index, member) in v.members.iter().enumerate() {
+            if index > 0 {
+                out.push_str(", ");
+            }
+            let name = v.sig.member_names.get(index).map(String::as_str).unwrap_or("?");
+            out.push_str(&format!("{}: {}", json_string(name), format_value_json(member.as_ref())));
+        }
+        out.push('}');
+        out
+    } else if let Some(v) = any.downcast_ref::<value_structure::Array>() {
+        let mut out = String::from("[");
+        for (index, elem) in v.values.iter().enumerate() {
+            if index > 0 {
+                out.push_str(", ");
+            }
+            out.push_str(&format_value_json(elem.as_ref()));
+        }
+        out.push(']');
+        out
+    } else if let Some(v) = any.downcast_ref::<value_structure::Blob>() {
+        format!("{{\"size\": {}}}", v.size)
+    } else if let Some(v) = any.downcast_ref::<value_structure::Pointer>() {
+        format!("\"{:#x}\"", v.value as usize)
+    } else {
+        "null".to_string()
+    }
+}
+
+/// Resolves an enum value to its symbolic constant via the call's attached
+/// `EnumSignature` (the trace's per-occurrence stand-in for a `gl.xml`
+/// enum group) first, then the `gl.xml`-registered name for the raw value
+/// (covers a value from a different enum group than the one the trace
+/// recorded), falling back to hex the way Khronos' `toHex` wrapper does
+/// when neither knows the value.
+fn format_enum(value: &value_structure::Enum) -> String {
+    match value.sig.values.iter().find(|candidate| candidate.value == value.value) {
+        Some(named) => named.name.clone(),
+        None => match trace::gl_enum_name(value.value) {
+            Some(name) => name.to_string(),
+            None => format!("{:#x}", value.value),
+        },
+    }
+}
+
+/// Resolves a bitmask to an OR-joined list of flag names, greedily matching
+/// the highest-value flags first so overlapping aliases (e.g. an
+/// `ALL_ATTRIB_BITS`-style flag that covers several narrower ones) resolve
+/// to the broadest name instead of its constituents. Any bits left over
+/// after the known flags are subtracted are appended in hex.
+fn format_bitmask(value: &value_structure::Bitmask) -> String {
+    let mut candidates: Vec<&signatures::BitmaskFlag> = value.sig.bitmask_flags.iter().collect();
+    candidates.sort_by(|a, b| b.value.cmp(&a.value));
+
+    let mut remaining = value.value;
+    let mut names = Vec::new();
+    for flag in candidates {
+        if flag.value != 0 && remaining & flag.value == flag.value {
+            names.push(flag.name.clone());
+            remaining &= !flag.value;
+        }
+    }
+    if remaining != 0 {
+        names.push(format!("{:#x}", remaining));
+    }
+    if names.is_empty() {
+        "0".to_string()
+    } else {
+        names.join(" | ")
+    }
+}
+
+fn format_struct(value: &value_structure::Struct) -> String {
+    let mut out = String::from("{");
+    for (index, member) in value.members.iter().enumerate() {
+        if index > 0 {
+            out.push_str(", ");
+        }
+        let name = value.sig.member_names.get(index).map(String::as_str).unwrap_or("?");
+        out.push_str(&format!("{}: {}", name, format_value(member.as_ref())));
+    }
+    out.push('}');
+    out
+}
+
+fn format_array(value: &value_structure::Array) -> String {
+    let mut out = String::from("[");
+    for (index, elem) in value.values.iter().enumerate() {
+        if index > 0 {
+            out.push_str(", ");
+        }
+        out.push_str(&format_value(elem.as_ref()));
+    }
+    out.push(']');
+    out
+}
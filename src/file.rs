@@ -2,13 +2,14 @@ use std::{
     cmp::min,
     error::Error,
     fs::File,
-    io::{self, Read, Seek},
-    mem::MaybeUninit,
+    io::{self, Read, Seek, Write},
     panic::Location,
     string::FromUtf8Error,
+    sync::mpsc,
+    thread,
 };
 
-use snap::raw::Decoder;
+use snap::raw::{Decoder, Encoder};
 
 use crate::trace;
 
@@ -25,6 +26,8 @@ pub enum SnappyError {
     DecompressionError(&'static Location<'static>, snap::Error),
     InsufficientData(&'static Location<'static>),
     ConversionError(&'static Location<'static>, String),
+    UnsupportedCodec(&'static Location<'static>, [u8; 2]),
+    PrefetchActive(&'static Location<'static>),
 }
 
 impl SnappyError {
@@ -38,6 +41,11 @@ impl SnappyError {
         Self::InvalidHeader(Location::caller())
     }
 
+    #[track_caller]
+    pub fn unsupported_codec(magic: [u8; 2]) -> Self {
+        Self::UnsupportedCodec(Location::caller(), magic)
+    }
+
     #[track_caller]
     pub fn decompression_error(error: snap::Error) -> Self {
         Self::DecompressionError(Location::caller(), error)
@@ -52,6 +60,17 @@ impl SnappyError {
     pub fn conversion_error(message: String) -> Self {
         Self::ConversionError(Location::caller(), message)
     }
+
+    /// Returned by `seek`/`build_index` on a `SnappyFile` opened via
+    /// `new_prefetching`: both reposition `snappy_file` directly, which the
+    /// background worker's independent, still-sequentially-advancing
+    /// `worker_file` knows nothing about, so serving either from the
+    /// prefetch channel would silently hand back bytes from the wrong
+    /// position instead of the one just sought to.
+    #[track_caller]
+    pub fn prefetch_active() -> Self {
+        Self::PrefetchActive(Location::caller())
+    }
 }
 
 impl std::fmt::Display for SnappyError {
@@ -84,6 +103,23 @@ impl std::fmt::Display for SnappyError {
                     location.line()
                 )
             }
+            SnappyError::UnsupportedCodec(location, magic) => {
+                write!(
+                    f,
+                    "Unsupported container codec {:?} at {}:{}",
+                    magic,
+                    location.file(),
+                    location.line()
+                )
+            }
+            SnappyError::PrefetchActive(location) => {
+                write!(
+                    f,
+                    "random access is unsupported on a prefetching SnappyFile at {}:{}",
+                    location.file(),
+                    location.line()
+                )
+            }
         }
     }
 }
@@ -115,12 +151,79 @@ impl From<FromUtf8Error> for SnappyError {
         SnappyError::conversion_error(value.to_string())
     }
 }
+/// Decompresses a single `[u32 compressed_len][compressed bytes]` chunk into
+/// `cache`, growing/refilling it as needed. Chunk framing is identical
+/// across codecs; only this step differs, which is what lets `SnappyFile`
+/// stay codec-agnostic and pick an implementation from the container's
+/// magic bytes at open time.
+pub trait ChunkSource: Send {
+    fn decompress_chunk(&mut self, compressed: &[u8], cache: &mut Vec<u8>) -> Result<(), SnappyError>;
+}
+
+struct SnappyCodec {
+    decoder: Decoder,
+}
+
+impl ChunkSource for SnappyCodec {
+    fn decompress_chunk(&mut self, compressed: &[u8], cache: &mut Vec<u8>) -> Result<(), SnappyError> {
+        let uncompressed_length = snap::raw::decompress_len(compressed)?;
+        cache.resize(uncompressed_length, 0);
+        self.decoder.decompress(compressed, cache)?;
+        Ok(())
+    }
+}
+
+struct ZlibCodec;
+
+impl ChunkSource for ZlibCodec {
+    fn decompress_chunk(&mut self, compressed: &[u8], cache: &mut Vec<u8>) -> Result<(), SnappyError> {
+        use flate2::read::ZlibDecoder;
+        cache.clear();
+        ZlibDecoder::new(compressed)
+            .read_to_end(cache)
+            .map_err(SnappyError::io_error)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "zstd")]
+struct ZstdCodec;
+
+#[cfg(feature = "zstd")]
+impl ChunkSource for ZstdCodec {
+    fn decompress_chunk(&mut self, compressed: &[u8], cache: &mut Vec<u8>) -> Result<(), SnappyError> {
+        cache.clear();
+        cache.extend_from_slice(&zstd::stream::decode_all(compressed).map_err(SnappyError::io_error)?);
+        Ok(())
+    }
+}
+
+fn select_codec(magic: [u8; 2]) -> Result<Box<dyn ChunkSource>, SnappyError> {
+    match &magic {
+        b"at" => Ok(Box::new(SnappyCodec { decoder: snap::raw::Decoder::new() })),
+        b"zl" => Ok(Box::new(ZlibCodec)),
+        #[cfg(feature = "zstd")]
+        b"zs" => Ok(Box::new(ZstdCodec)),
+        _ => Err(SnappyError::unsupported_codec(magic)),
+    }
+}
+
+/// One chunk as delivered by a [`PrefetchWorker`]: the uncompressed bytes
+/// plus the file offset its compressed-length prefix started at, so
+/// `chunk_offset`/`Position` bookkeeping survives moving decompression to a
+/// background thread.
+struct PrefetchedChunk {
+    offset: usize,
+    data: Vec<u8>,
+}
+
 pub struct SnappyFile {
     snappy_file: File,
-    snappy_decoder: Decoder,
+    decoder: Box<dyn ChunkSource>,
     cache: Vec<u8>,
     cache_pos: usize,
     chunk_offset: usize,
+    prefetch: Option<mpsc::Receiver<PrefetchedChunk>>,
 }
 
 impl SnappyFile {
@@ -129,24 +232,64 @@ impl SnappyFile {
         let mut buffer: [u8; 2] = [0; 2];
 
         snappy_file.read_exact(&mut buffer).unwrap();
-        if &buffer == b"at" {
-            Ok(Self {
-                snappy_file,
-                snappy_decoder: snap::raw::Decoder::new(),
-                cache: Vec::new(),
-                cache_pos: 0,
-                chunk_offset: 0,
-            })
-        } else {
-            Err(SnappyError::invalid_header())
-        }
+        let decoder = select_codec(buffer)?;
+        Ok(Self {
+            snappy_file,
+            decoder,
+            cache: Vec::new(),
+            cache_pos: 0,
+            chunk_offset: 0,
+            prefetch: None,
+        })
     }
-    fn ensure_cache_capacity(&mut self, size: usize) {
-        if size > self.cache.capacity() {
-            self.cache.resize(size, 0);
-        }
-        self.cache_pos = 0;
+    /// Like `new`, but hands the compressed file and a decoder of its own off
+    /// to a background thread that reads and decompresses chunks ahead of
+    /// the parser into a bounded channel, so IO and decompression overlap
+    /// with parsing instead of the parser stalling on both. Only sequential
+    /// reads are served this way; `seek`/`build_index` reposition the
+    /// foreground file directly, which the worker's own independent cursor
+    /// knows nothing about, so both reject a prefetching `SnappyFile` with
+    /// `SnappyError::prefetch_active` instead of silently serving bytes
+    /// from the wrong position — use the plain `new` path for random access.
+    pub fn new_prefetching(path: &str, queue_depth: usize) -> Result<Self, SnappyError> {
+        let mut base = Self::new(path)?;
+
+        let mut worker_file = File::open(path)?;
+        let mut magic = [0u8; 2];
+        worker_file.read_exact(&mut magic)?;
+        let mut worker_decoder = select_codec(magic)?;
+        worker_file.seek(io::SeekFrom::Start(base.snappy_file.stream_position()?))?;
+
+        let (sender, receiver) = mpsc::sync_channel(queue_depth.max(1));
+        thread::spawn(move || {
+            loop {
+                let offset = match worker_file.stream_position() {
+                    Ok(pos) => pos as usize,
+                    Err(_) => break,
+                };
+                let mut len_buf = [0u8; 4];
+                if worker_file.read_exact(&mut len_buf).is_err() {
+                    break;
+                }
+                let compressed_length = u32::from_le_bytes(len_buf) as usize;
+                let mut compressed = vec![0u8; compressed_length];
+                if worker_file.read_exact(&mut compressed).is_err() {
+                    break;
+                }
+                let mut data = Vec::new();
+                if worker_decoder.decompress_chunk(&compressed, &mut data).is_err() {
+                    break;
+                }
+                if sender.send(PrefetchedChunk { offset, data }).is_err() {
+                    break;
+                }
+            }
+        });
+
+        base.prefetch = Some(receiver);
+        Ok(base)
     }
+
     fn read_compressed_length(&mut self) -> Result<usize, SnappyError> {
         let mut buffer = [0u8; 4];
         self.snappy_file.read_exact(&mut buffer)?;
@@ -154,18 +297,19 @@ impl SnappyFile {
         Ok(chunk_len)
     }
     fn load_next_chunk(&mut self) -> Result<(), SnappyError> {
+        if let Some(receiver) = &self.prefetch {
+            let chunk = receiver.recv().map_err(|_| SnappyError::insufficient_data())?;
+            self.chunk_offset = chunk.offset;
+            self.cache = chunk.data;
+            self.cache_pos = 0;
+            return Ok(());
+        }
         self.chunk_offset = self.snappy_file.stream_position()? as usize;
         let compressed_length = self.read_compressed_length()?;
         let mut buffer = vec![0u8; compressed_length];
-        match self.snappy_file.read_exact(&mut buffer) {
-            Ok(_) => {
-                let uncompressed_length = snap::raw::decompress_len(&buffer)?;
-                self.ensure_cache_capacity(uncompressed_length);
-                self.snappy_decoder.decompress(&buffer, &mut self.cache)?
-            }
-            Err(err) => Err(err)?,
-        };
-
+        self.snappy_file.read_exact(&mut buffer)?;
+        self.decoder.decompress_chunk(&buffer, &mut self.cache)?;
+        self.cache_pos = 0;
         Ok(())
     }
     fn cache_remaining(&self) -> usize {
@@ -194,18 +338,10 @@ impl SnappyFile {
         Ok(())
     }
 
-    pub fn read_type<T: Sized>(&mut self) -> Result<T, SnappyError> {
-        let mut tmp = MaybeUninit::<T>::uninit();
-        let mut buffer = vec![0u8; size_of::<T>()];
-        self.read_bytes(&mut buffer)?;
-        unsafe {
-            std::ptr::copy_nonoverlapping(
-                buffer.as_ptr(),
-                tmp.as_mut_ptr() as *mut u8,
-                size_of::<T>(),
-            );
-        }
-        Ok(unsafe { tmp.assume_init() })
+    /// Reads a fixed-width primitive via its [`FromTrace`] impl — the sound,
+    /// portable replacement for the old raw-bytes `read_type`.
+    pub fn read<T: FromTrace>(&mut self) -> Result<T, SnappyError> {
+        T::read(self)
     }
     pub fn read_varint(&mut self) -> Result<usize, SnappyError> {
         let mut return_value: usize = 0;
@@ -247,7 +383,7 @@ impl SnappyFile {
         }
     }
     pub fn read_signed_varint(&mut self) -> Result<i64, SnappyError> {
-        match self.read_type::<u8>() {
+        match self.read::<u8>() {
             Ok(val) => match val {
                 n if trace::Type::TypeSint as u8 == n => Ok(-(self.read_varint()? as i64)),
                 n if trace::Type::TypeUint as u8 == n => Ok(self.read_varint()? as i64),
@@ -256,4 +392,189 @@ impl SnappyFile {
             Err(_) => Ok(0i64),
         }
     }
+
+    /// Re-seeks the underlying file to `pos.chunk_offset`, decompresses that
+    /// one chunk into `cache`, and restores the in-chunk read cursor, so a
+    /// previously recorded `Position` can be replayed from again.
+    pub fn seek(&mut self, pos: Position) -> Result<(), SnappyError> {
+        if self.prefetch.is_some() {
+            return Err(SnappyError::prefetch_active());
+        }
+        self.snappy_file.seek(io::SeekFrom::Start(pos.chunk_offset as u64))?;
+        self.load_next_chunk()?;
+        self.cache_pos = pos.position_in_chunk;
+        Ok(())
+    }
+
+    /// Scans the remainder of the file from the current position once,
+    /// recording `(file_offset, cumulative_uncompressed_len)` for every
+    /// chunk without retaining the decompressed bytes. The resulting table
+    /// lets `seek_to_uncompressed` binary-search its way to any uncompressed
+    /// offset without a previously saved `Position`.
+    pub fn build_index(&mut self) -> Result<Vec<(usize, usize)>, SnappyError> {
+        if self.prefetch.is_some() {
+            return Err(SnappyError::prefetch_active());
+        }
+        let mut index = Vec::new();
+        let mut cumulative = 0usize;
+        let mut scratch = Vec::new();
+        loop {
+            let chunk_offset = self.snappy_file.stream_position()? as usize;
+            let mut len_buf = [0u8; 4];
+            if self.snappy_file.read_exact(&mut len_buf).is_err() {
+                break;
+            }
+            let compressed_length = u32::from_le_bytes(len_buf) as usize;
+            let mut buffer = vec![0u8; compressed_length];
+            self.snappy_file.read_exact(&mut buffer)?;
+            // Goes through `self.decoder` rather than `snap::raw::decompress_len`
+            // directly so a "zl"/"zs" trace's chunk length is measured with
+            // the same codec that will actually decompress it, instead of
+            // always assuming snappy framing.
+            self.decoder.decompress_chunk(&buffer, &mut scratch)?;
+            cumulative += scratch.len();
+            index.push((chunk_offset, cumulative));
+        }
+        Ok(index)
+    }
+
+    /// Binary-searches a `build_index` table for the chunk owning
+    /// `offset` (an offset into the uncompressed byte stream) and seeks there.
+    pub fn seek_to_uncompressed(&mut self, offset: usize, index: &[(usize, usize)]) -> Result<(), SnappyError> {
+        let chunk_idx = index.partition_point(|&(_, cumulative_end)| cumulative_end <= offset);
+        if chunk_idx >= index.len() {
+            return Err(SnappyError::insufficient_data());
+        }
+        let chunk_start = if chunk_idx == 0 { 0 } else { index[chunk_idx - 1].1 };
+        let (chunk_offset, _) = index[chunk_idx];
+        self.seek(Position {
+            chunk_offset,
+            position_in_chunk: offset - chunk_start,
+        })
+    }
+}
+
+/// Decodes a fixed-width primitive from a `SnappyFile` as explicit
+/// little-endian bytes, rather than the raw, endianness- and
+/// padding-unaware `MaybeUninit` copy the old `read_type` used.
+pub trait FromTrace: Sized {
+    fn read(src: &mut SnappyFile) -> Result<Self, SnappyError>;
+}
+
+macro_rules! impl_from_trace {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl FromTrace for $t {
+                fn read(src: &mut SnappyFile) -> Result<Self, SnappyError> {
+                    let mut buf = [0u8; std::mem::size_of::<$t>()];
+                    src.read_bytes(&mut buf)?;
+                    Ok(<$t>::from_le_bytes(buf))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_trace!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+/// Write-side counterpart to `FromTrace`: serializes `Self` to little-endian
+/// bytes, so a trace written on a big-endian host round-trips through
+/// `FromTrace::read` on any host instead of only the one that wrote it.
+pub trait ToTrace {
+    fn write(&self, dst: &mut SnappyWriter) -> Result<(), SnappyError>;
+}
+
+macro_rules! impl_to_trace {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl ToTrace for $t {
+                fn write(&self, dst: &mut SnappyWriter) -> Result<(), SnappyError> {
+                    dst.write_bytes(&self.to_le_bytes())
+                }
+            }
+        )*
+    };
+}
+
+impl_to_trace!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+/// Mirror of `SnappyFile` for the write direction: buffers uncompressed bytes
+/// and flushes them as snappy-compressed chunks using the same
+/// `[u32 compressed_len][compressed bytes]` framing the reader expects.
+pub struct SnappyWriter {
+    snappy_file: File,
+    snappy_encoder: Encoder,
+    buffer: Vec<u8>,
+}
+
+impl SnappyWriter {
+    pub fn create(path: &str) -> Result<Self, SnappyError> {
+        let mut snappy_file = File::create(path)?;
+        snappy_file.write_all(b"at")?;
+        Ok(Self {
+            snappy_file,
+            snappy_encoder: Encoder::new(),
+            buffer: Vec::new(),
+        })
+    }
+
+    pub fn write_bytes(&mut self, buf: &[u8]) -> Result<(), SnappyError> {
+        self.buffer.extend_from_slice(buf);
+        Ok(())
+    }
+
+    /// Writes `value` via `ToTrace`, i.e. as little-endian bytes, so a trace
+    /// written on a big-endian host round-trips through `FromTrace::read` on
+    /// any host instead of only the one that wrote it.
+    pub fn write_type<T: ToTrace>(&mut self, value: &T) -> Result<(), SnappyError> {
+        value.write(self)
+    }
+
+    pub fn write_varint(&mut self, mut value: usize) -> Result<(), SnappyError> {
+        loop {
+            let mut byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value != 0 {
+                byte |= 0x80;
+            }
+            self.write_bytes(&[byte])?;
+            if value == 0 {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn write_string(&mut self, value: &str) -> Result<(), SnappyError> {
+        self.write_varint(value.len())?;
+        self.write_bytes(value.as_bytes())
+    }
+
+    pub fn write_signed_varint(&mut self, value: i64) -> Result<(), SnappyError> {
+        if value < 0 {
+            self.write_type(&(trace::Type::TypeSint as u8))?;
+            self.write_varint((-value) as usize)
+        } else {
+            self.write_type(&(trace::Type::TypeUint as u8))?;
+            self.write_varint(value as usize)
+        }
+    }
+
+    /// Compresses and flushes whatever has been buffered so far as a single chunk.
+    /// A no-op when nothing has been written since the last flush.
+    pub fn flush_chunk(&mut self) -> Result<(), SnappyError> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let compressed = self.snappy_encoder.compress_vec(&self.buffer)?;
+        self.snappy_file
+            .write_all(&(compressed.len() as u32).to_le_bytes())?;
+        self.snappy_file.write_all(&compressed)?;
+        self.buffer.clear();
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<(), SnappyError> {
+        self.flush_chunk()
+    }
 }
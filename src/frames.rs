@@ -0,0 +1,99 @@
+use std::ops::Range;
+
+use crate::call::Call;
+use crate::parser::ParserError;
+
+/// `CallFlagEndFrame`'s bit from `trace::CallFlags` — duplicated here rather
+/// than exposed from `trace` since it's the only flag this module cares
+/// about. `CallFlagSwapbuffers` (48) already carries this bit, so checking
+/// it alone is enough to catch both.
+const CALL_FLAG_END_FRAME: u16 = 32;
+
+/// Groups a flat run of `Call`s into frames, the navigation primitive
+/// apitrace-style viewers build "jump to frame N" on top of. A frame ends
+/// at the first call whose flag carries `CallFlagEndFrame` (set on both
+/// present calls and swapbuffers calls, see `Call::lookup_call_flag`); a
+/// debug marker never ends one by itself since marker flags don't set that
+/// bit. A trace that never presents is a single open frame, and any calls
+/// left over after the last present form a trailing partial frame.
+pub struct Frames {
+    calls: Vec<Call>,
+    /// End-exclusive call index of each frame — `ends[i]` is one past the
+    /// last call of frame `i`.
+    ends: Vec<usize>,
+}
+
+impl Frames {
+    /// Drains a `Calls` stream (or any other `Call` iterator) into frames,
+    /// propagating the first parse error encountered.
+    pub fn collect(calls: impl Iterator<Item = Result<Call, ParserError>>) -> Result<Self, ParserError> {
+        let calls: Vec<Call> = calls.collect::<Result<_, _>>()?;
+        Ok(Self::from_calls(calls))
+    }
+
+    /// Groups an already-parsed run of calls into frames.
+    pub fn from_calls(calls: Vec<Call>) -> Self {
+        let mut ends: Vec<usize> = calls
+            .iter()
+            .enumerate()
+            .filter(|(_, call)| call.sig.flag.unwrap_or(0) & CALL_FLAG_END_FRAME != 0)
+            .map(|(index, _)| index + 1)
+            .collect();
+
+        if ends.last().copied() != Some(calls.len()) {
+            ends.push(calls.len());
+        }
+
+        Frames { calls, ends }
+    }
+
+    pub fn frame_count(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// The `[start, end)` call-index range spanned by `frame`.
+    pub fn frame_range(&self, frame: usize) -> Option<Range<usize>> {
+        let end = *self.ends.get(frame)?;
+        let start = if frame == 0 { 0 } else { self.ends[frame - 1] };
+        Some(start..end)
+    }
+
+    pub fn frame(&self, frame: usize) -> Option<&[Call]> {
+        self.frame_range(frame).map(|range| &self.calls[range])
+    }
+
+    /// Which frame a call (by its position in the original stream) falls into.
+    pub fn frame_of_call(&self, call_index: usize) -> Option<usize> {
+        self.ends.iter().position(|&end| call_index < end)
+    }
+
+    pub fn calls(&self) -> &[Call] {
+        &self.calls
+    }
+}
+
+impl<'a> IntoIterator for &'a Frames {
+    type Item = (usize, &'a [Call]);
+    type IntoIter = FrameIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        FrameIter { frames: self, next: 0 }
+    }
+}
+
+/// Yields `(frame_index, calls)` pairs in order.
+pub struct FrameIter<'a> {
+    frames: &'a Frames,
+    next: usize,
+}
+
+impl<'a> Iterator for FrameIter<'a> {
+    type Item = (usize, &'a [Call]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let frame = self.frames.frame(self.next)?;
+        let index = self.next;
+        self.next += 1;
+        Some((index, frame))
+    }
+}
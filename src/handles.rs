@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use crate::region::Map;
+
+/// One GL object ID namespace. Each namespace gets its own `Map<u32>`
+/// remapping trace-recorded handles to the IDs GL actually handed back on
+/// this replay, since e.g. a trace buffer named `7` and a trace texture
+/// named `7` are unrelated handles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Namespace {
+    Buffer,
+    Texture,
+    Sampler,
+    VertexArray,
+    Framebuffer,
+    Renderbuffer,
+    Program,
+    Query,
+    Sync,
+}
+
+/// Generalizes `lookup_uniform_location` to every GL object namespace
+/// (buffers, textures, samplers, VAOs, framebuffers, programs, queries,
+/// sync objects), so retrace callbacks no longer need ad-hoc per-call maps.
+#[derive(Debug, Default)]
+pub struct HandleRemapper {
+    namespaces: HashMap<Namespace, Map<u32>>,
+}
+
+impl HandleRemapper {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn namespace(&mut self, ns: Namespace) -> &mut Map<u32> {
+        self.namespaces.entry(ns).or_insert_with(Map::new)
+    }
+
+    /// Records that `trace_name` (as seen in a `glGen*`/`glCreate*` call's
+    /// result) maps to `real_name`, the handle GL actually returned here.
+    pub fn declare(&mut self, ns: Namespace, trace_name: u32, real_name: u32) -> u32 {
+        self.namespace(ns).insert(trace_name, real_name);
+        real_name
+    }
+
+    /// Resolves a trace-recorded handle to its replay-side counterpart,
+    /// reusing `Map::lookup_uniform_location`'s range-coalescing lookup so
+    /// contiguously allocated ID blocks (e.g. `glGenTextures(n, ...)`) map
+    /// compactly. A handle that was never `declare`d resolves by its offset
+    /// from the nearest declared handle below it; only when there is none
+    /// below does it map (and get recorded) as itself.
+    pub fn resolve(&mut self, ns: Namespace, trace_name: u32) -> u32 {
+        self.namespace(ns).lookup_uniform_location(trace_name)
+    }
+
+    /// Forgets a handle after it's deleted on the trace side (`glDelete*`).
+    pub fn release(&mut self, ns: Namespace, trace_name: u32) {
+        self.namespace(ns).remove(&trace_name);
+    }
+}
@@ -1,6 +1,8 @@
 extern crate regex;
 extern crate sdl3;
 extern crate snap;
+extern crate flate2;
+extern crate phf;
 
 mod file;
 mod parser;
@@ -8,7 +10,14 @@ mod trace;
 mod value_structure;
 mod call;
 mod signatures;
+mod region;
+mod frames;
+mod markers;
+mod handles;
 mod retracer;
+mod replay;
+mod writer;
+mod dump;
 mod test;
 #[path ="../helpers/try.rs"]
 mod r#try;
@@ -21,12 +30,27 @@ use sdl3::{EventPump, Sdl};
 use std::error::Error;
 use std::ffi::c_void;
 
+use crate::dump::DumpFormat;
 use crate::parser::Parser;
 use crate::retracer::Retracer;
 
-
+/// `cargo run -- dump [--json] <trace>` — inspect a trace without retracing it.
+fn run_dump(args: &[String]) {
+    let json = args.iter().any(|arg| arg == "--json");
+    let path = args.iter().find(|arg| !arg.starts_with("--")).expect("usage: dump [--json] <trace>");
+    let mut parser = Parser::new(path).unwrap();
+    let format = if json { DumpFormat::Json } else { DumpFormat::Text };
+    let stdout = std::io::stdout();
+    let mut handle = stdout.lock();
+    dump::dump_trace(&mut parser, &mut handle, format).unwrap();
+}
 
 pub fn main() {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.is_empty() && args[0] == "dump" {
+        run_dump(&args.split_off(1));
+        return;
+    }
     //test::test();
     /*let mut parser = Parser::new("../apitrace/hl2.trace").unwrap();
     let mut retracer = Retracer::init();
@@ -42,10 +66,10 @@ pub fn main() {
 
     }*/
     /*parser.parse_properties().unwrap();
-        let _ = parser.snappy.read_type::<u8>().unwrap();
+        let _ = parser.snappy.read::<u8>().unwrap();
         let _ = parser.snappy.read_varint().unwrap();
         println!("{:?} | derived API: {:?}", parser.parse_function_sig().unwrap(), parser.api);
-        let _ = parser.snappy.read_type::<u8>().unwrap();
+        let _ = parser.snappy.read::<u8>().unwrap();
         let _ = parser.snappy.read_varint().unwrap();
         println!("{:?} | derived API: {:?}", parser.parse_function_sig().unwrap(), parser.api);
     */
@@ -0,0 +1,91 @@
+use crate::call::Call;
+use crate::value_structure::{self, Value};
+
+/// `CallFlagMarkerPush`'s bit from `trace::CallFlags`, duplicated here the
+/// same way `frames::CALL_FLAG_END_FRAME` is — this module only ever cares
+/// about these two bits.
+const CALL_FLAG_MARKER_PUSH: u16 = 512;
+const CALL_FLAG_MARKER_POP: u16 = 1024;
+
+/// One debug-marker region (a `glPushDebugGroup`/`D3DPERF_BeginEvent`-style
+/// span), with the plain calls and nested sub-groups it contains in
+/// call-stream order.
+#[derive(Debug, Default)]
+pub struct MarkerNode {
+    pub label: String,
+    pub start_call: usize,
+    pub end_call: usize,
+    pub children: Vec<MarkerNode>,
+    pub calls: Vec<usize>,
+}
+
+impl MarkerNode {
+    fn new(label: String, start_call: usize) -> Self {
+        MarkerNode { label, start_call, end_call: start_call, children: Vec::new(), calls: Vec::new() }
+    }
+}
+
+/// Walks a parsed `Call` sequence and assembles `CallFlagMarkerPush`/
+/// `CallFlagMarkerPop` pairs into a tree of named regions. A plain call
+/// attaches to whichever group is currently open, or is returned as a
+/// top-level call if none is. A push left open when the stream ends is
+/// auto-closed at the last call index, so a dangling group still shows up
+/// instead of silently vanishing.
+///
+/// Returns the top-level marker tree alongside the call indices that never
+/// fell inside any group.
+pub fn build_marker_tree(calls: &[Call]) -> (Vec<MarkerNode>, Vec<usize>) {
+    let mut roots = Vec::new();
+    let mut top_level_calls = Vec::new();
+    let mut stack: Vec<MarkerNode> = Vec::new();
+
+    for (index, call) in calls.iter().enumerate() {
+        let flag = call.sig.flag.unwrap_or(0);
+        if flag & CALL_FLAG_MARKER_PUSH != 0 {
+            stack.push(MarkerNode::new(marker_label(call), index));
+            continue;
+        }
+        if flag & CALL_FLAG_MARKER_POP != 0 {
+            if let Some(mut node) = stack.pop() {
+                node.end_call = index;
+                attach(&mut stack, &mut roots, node);
+            }
+            continue;
+        }
+        match stack.last_mut() {
+            Some(open) => open.calls.push(index),
+            None => top_level_calls.push(index),
+        }
+    }
+
+    // Unbalanced pushes: close the innermost dangling group first so each
+    // one still nests under whichever group opened before it.
+    let last_call = calls.len().saturating_sub(1);
+    while let Some(mut node) = stack.pop() {
+        node.end_call = last_call;
+        attach(&mut stack, &mut roots, node);
+    }
+
+    (roots, top_level_calls)
+}
+
+fn attach(stack: &mut Vec<MarkerNode>, roots: &mut Vec<MarkerNode>, node: MarkerNode) {
+    match stack.last_mut() {
+        Some(parent) => parent.children.push(node),
+        None => roots.push(node),
+    }
+}
+
+/// The push call's label argument, e.g. `glPushDebugGroup`'s `message` or
+/// `D3DPERF_BeginEvent`'s `wszName` — the first string-typed argument.
+fn marker_label(call: &Call) -> String {
+    call.args
+        .iter()
+        .find_map(|arg| {
+            let any = arg.as_any();
+            any.downcast_ref::<value_structure::VString>()
+                .map(|v| v.value.clone())
+                .or_else(|| any.downcast_ref::<value_structure::WString>().map(|v| v.value.clone()))
+        })
+        .unwrap_or_else(|| "<unnamed>".to_string())
+}
@@ -8,9 +8,10 @@ use crate::{
     call::{Call, CallDetail, CallError},
     file::{SnappyError, SnappyFile},
     signatures::{
-        BitmaskFlag, BitmaskSignature, EnumSignature, EnumValue, FunctionSignature, StructSignature,
+        BacktraceFrame, BitmaskFlag, BitmaskSignature, ContextFrame, ErrorStack, EnumSignature,
+        EnumValue, FunctionSignature, FunctionSignatureError, SignatureRegistry, StructSignature,
     },
-    trace::{self, Event},
+    trace::{self, BacktraceDetail, Event},
     value_structure::{self, Value},
 };
 
@@ -34,6 +35,9 @@ pub enum ParserError {
     VersionMismatch(&'static Location<'static>),
     SnappyError(&'static Location<'static>, SnappyError),
     CallFormingError(&'static Location<'static>, CallError),
+    UnknownEvent(&'static Location<'static>, u8),
+    UnknownCallDetail(&'static Location<'static>, u8),
+    UnknownValueType(&'static Location<'static>, u8),
 }
 
 impl ParserError {
@@ -51,6 +55,21 @@ impl ParserError {
     pub fn call_forming_error(error: CallError) -> Self {
         Self::CallFormingError(Location::caller(), error)
     }
+
+    #[track_caller]
+    pub fn unknown_event(tag: u8) -> Self {
+        Self::UnknownEvent(Location::caller(), tag)
+    }
+
+    #[track_caller]
+    pub fn unknown_call_detail(tag: u8) -> Self {
+        Self::UnknownCallDetail(Location::caller(), tag)
+    }
+
+    #[track_caller]
+    pub fn unknown_value_type(tag: u8) -> Self {
+        Self::UnknownValueType(Location::caller(), tag)
+    }
 }
 
 impl From<SnappyError> for ParserError {
@@ -85,6 +104,15 @@ impl std::fmt::Display for ParserError {
                     location.line()
                 )
             }
+            ParserError::UnknownEvent(location, tag) => {
+                write!(f, "Unknown event tag {:#x} at {}:{}", tag, location.file(), location.line())
+            }
+            ParserError::UnknownCallDetail(location, tag) => {
+                write!(f, "Unknown call detail tag {:#x} at {}:{}", tag, location.file(), location.line())
+            }
+            ParserError::UnknownValueType(location, tag) => {
+                write!(f, "Unknown value type tag {:#x} at {}:{}", tag, location.file(), location.line())
+            }
         }
     }
 }
@@ -111,6 +139,10 @@ pub struct Parser {
     enums: Vec<Option<Rc<EnumSignature>>>,
     structs: Vec<Option<Rc<StructSignature>>>,
     bitmasks: Vec<Option<Rc<BitmaskSignature>>>,
+    backtrace_frames: Vec<Option<Rc<BacktraceFrame>>>,
+    lenient: bool,
+    pub diagnostics: Vec<String>,
+    pub signature_errors: ErrorStack,
 }
 
 impl Parser {
@@ -135,9 +167,52 @@ impl Parser {
             enums: Vec::new(),
             structs: Vec::new(),
             bitmasks: Vec::new(),
+            backtrace_frames: Vec::new(),
+            lenient: false,
+            diagnostics: Vec::new(),
+            signature_errors: ErrorStack::new(true),
         })
     }
 
+    /// Enables lenient mode: unknown event/detail/value tags are recorded as
+    /// diagnostics instead of erroring, and the parser resynchronizes by
+    /// scanning forward to the next `EventEnter`/`EventLeave` boundary
+    /// instead of aborting the whole parse. Also switches `signature_errors`
+    /// out of strict mode, so a malformed signature is recorded there
+    /// instead of aborting the parse the same way an unknown tag no longer
+    /// does.
+    pub fn set_lenient(&mut self, lenient: bool) {
+        self.lenient = lenient;
+        self.signature_errors.set_strict(!lenient);
+    }
+
+    /// Records a signature-parsing failure into `signature_errors`, tagged
+    /// with the breadcrumb trail of which signature (and field/index) was
+    /// being decoded. In strict mode this returns the same failure back to
+    /// the caller (the pre-`ErrorStack` fail-fast behavior); otherwise it's
+    /// stored and `fallback` is returned so parsing can move on to the next
+    /// signature.
+    #[track_caller]
+    fn signature_fallback<T>(
+        &mut self,
+        id: usize,
+        context: Vec<ContextFrame>,
+        error: SnappyError,
+        fallback: T,
+    ) -> Result<T, ParserError> {
+        let mut error = FunctionSignatureError::from(error);
+        for frame in context {
+            error = error.with_context(frame);
+        }
+        match self.signature_errors.record(id, error) {
+            Ok(()) => Ok(fallback),
+            Err(signature_error) => match signature_error.error {
+                FunctionSignatureError::SnappyError(_, error, _) => Err(ParserError::snappy_error(error)),
+                FunctionSignatureError::ParserError(_, error, _) => Err(error),
+            },
+        }
+    }
+
     pub fn parse_properties(&mut self) -> Result<(), ParserError> {
         'properties_parser: loop {
             let name = match self.snappy.read_string() {
@@ -157,7 +232,7 @@ impl Parser {
 
     pub fn parse_call(&mut self) -> Result<Call, ParserError> {
         loop {
-            match self.snappy.read_type::<u8>() {
+            match self.snappy.read::<u8>() {
                 Ok(val) => match val {
                     n if Event::EventEnter as u8 == n => {
                         let thread_id = self.snappy.read_varint()?;
@@ -190,7 +265,18 @@ impl Parser {
                             return Ok(call.unwrap());
                         }
                     }
-                    _ => panic!("Unknown Event type"),
+                    _ => {
+                        if self.lenient {
+                            self.diagnostics.push(format!(
+                                "unknown event tag {:#x} at {:?}; resynchronizing",
+                                val,
+                                self.snappy.get_current_offset()
+                            ));
+                            self.call_list.clear();
+                        } else {
+                            return Err(ParserError::unknown_event(val));
+                        }
+                    }
                 },
 
                 Err(_) => {
@@ -214,23 +300,47 @@ impl Parser {
         &mut map[index]
     }
 
+    /// Snapshots every signature decoded so far into a navigable
+    /// `SignatureRegistry`. Can be called at any point during parsing; ids
+    /// not yet encountered simply aren't in the snapshot.
+    pub fn signature_registry(&self) -> SignatureRegistry {
+        SignatureRegistry::new(
+            self.functions.clone(),
+            self.enums.clone(),
+            self.structs.clone(),
+            self.bitmasks.clone(),
+        )
+    }
+
     pub fn parse_call_detail(&mut self, call: &mut Call) -> Result<bool, ParserError> {
         loop {
-            match self.snappy.read_type::<u8>() {
+            match self.snappy.read::<u8>() {
                 Err(_) => return Ok(false),
                 Ok(val) => {
                     match val {
                         n if CallDetail::CallEnd as u8 == n => return Ok(true),
                         n if CallDetail::CallArg as u8 == n => self.parse_arg(call)?,
                         n if CallDetail::CallRet as u8 == n => call.ret = self.parse_value()?,
-                        n if CallDetail::CallBacktrace as u8 == n => {} //TODO
+                        n if CallDetail::CallBacktrace as u8 == n => {
+                            call.backtrace = self.parse_backtrace()?;
+                        }
                         n if CallDetail::CallFlags as u8 == n => {
                             let flag = self.snappy.read_varint()?;
                             if flag & 1 == 1 {
                                 call.sig.flag = Some(call.sig.flag.unwrap_or(0) | 1);
                             }
                         }
-                        _ => panic!("Unknown call detail"),
+                        _ => {
+                            if self.lenient {
+                                self.diagnostics.push(format!(
+                                    "unknown call detail tag {:#x} at {:?}; ending call early",
+                                    val,
+                                    self.snappy.get_current_offset()
+                                ));
+                                return Ok(false);
+                            }
+                            return Err(ParserError::unknown_call_detail(val));
+                        }
                     }
                 }
             };
@@ -251,7 +361,7 @@ impl Parser {
     }
 
     fn parse_value(&mut self) -> Result<Option<Box<dyn Value>>, ParserError> {
-        match self.snappy.read_type::<u8>() {
+        match self.snappy.read::<u8>() {
             Err(_) => return Err(ParserError::snappy_error(SnappyError::insufficient_data())),
             Ok(val) => match val {
                 n if trace::Type::TypeNull as u8 == n => {
@@ -275,12 +385,12 @@ impl Parser {
                 },
                 n if trace::Type::TypeFloat as u8 == n => {
                     return Ok(Some(Box::new(value_structure::Float {
-                        value: self.snappy.read_type::<f32>()?,
+                        value: self.snappy.read::<f32>()?,
                     })));
                 },
                 n if trace::Type::TypeDouble as u8 == n => {
                     return Ok(Some(Box::new(value_structure::Double {
-                        value: self.snappy.read_type::<f64>()?,
+                        value: self.snappy.read::<f64>()?,
                     })));
                 },
                 n if trace::Type::TypeString as u8 == n => {
@@ -344,35 +454,100 @@ impl Parser {
                         value: self.snappy.read_varint()? as *mut std::ffi::c_void,
                     })));
                 }
-                n if trace::Type::TypeRepr as u8 == n => todo!(),
-                n if trace::Type::TypeWstring as u8 == n => todo!(),
+                n if trace::Type::TypeRepr as u8 == n => {
+                    let human = self
+                        .parse_value()?
+                        .unwrap_or_else(|| Box::new(value_structure::None {}));
+                    let machine = self
+                        .parse_value()?
+                        .unwrap_or_else(|| Box::new(value_structure::None {}));
+                    return Ok(Some(Box::new(value_structure::Repr { human, machine })));
+                },
+                n if trace::Type::TypeWstring as u8 == n => {
+                    let len = self.snappy.read_varint()?;
+                    let mut units = Vec::with_capacity(len);
+                    for _ in 0..len {
+                        units.push(self.snappy.read::<u16>()?);
+                    }
+                    let value: String = char::decode_utf16(units)
+                        .map(|unit| unit.unwrap_or(char::REPLACEMENT_CHARACTER))
+                        .collect();
+                    return Ok(Some(Box::new(value_structure::WString { value })));
+                },
 
-                _ => panic!("Unknown type"),
+                _ => {
+                    if self.lenient {
+                        self.diagnostics.push(format!(
+                            "unknown value type tag {:#x} at {:?}; treating as null",
+                            val,
+                            self.snappy.get_current_offset()
+                        ));
+                        return Ok(Some(Box::new(value_structure::None {})));
+                    }
+                    return Err(ParserError::unknown_value_type(val));
+                }
             },
         }
     }
 
+    /// Consumes a cached function signature's declaration (name + arg
+    /// names) when it is read again from before the position it was first
+    /// decoded at (e.g. after seeking back); the cached copy is kept.
+    fn skip_function_sig_refresh(&mut self) -> Result<(), SnappyError> {
+        let _ = self.snappy.read_string()?;
+        let num_args = self.snappy.read_varint()?;
+        for _ in 0..num_args {
+            let _ = self.snappy.read_string()?;
+        }
+        Ok(())
+    }
+
     fn parse_function_sig(&mut self) -> Result<FunctionSignature, ParserError> {
         let id = self.snappy.read_varint()?;
+        let sig_frame = || ContextFrame::Signature { kind: "FunctionSignature", id };
         let function_signature_cached = Parser::lookup(&mut self.functions, id);
         match function_signature_cached {
             Some(val) => {
+                let val = val.clone();
                 if self.snappy.get_current_offset() < *val.state.as_ref().unwrap() {
-                    let _ = self.snappy.read_string()?;
-                    let num_args = self.snappy.read_varint()?;
-                    for _ in 0..num_args {
-                        let _ = self.snappy.read_string()?;
+                    if let Err(err) = self.skip_function_sig_refresh() {
+                        return self.signature_fallback(id, vec![sig_frame()], err, val);
                     }
                 }
-                return Ok(val.clone());
+                return Ok(val);
             }
             None => {}
         }
-        let name = self.snappy.read_string()?;
-        let num_args = self.snappy.read_varint()?;
+        let name = match self.snappy.read_string() {
+            Ok(name) => name,
+            Err(err) => {
+                return self.signature_fallback(id, vec![sig_frame()], err, FunctionSignature { id, ..Default::default() });
+            }
+        };
+        let num_args = match self.snappy.read_varint() {
+            Ok(num_args) => num_args,
+            Err(err) => {
+                return self.signature_fallback(
+                    id,
+                    vec![sig_frame()],
+                    err,
+                    FunctionSignature { id, name, ..Default::default() },
+                );
+            }
+        };
         let mut arg_names = Vec::with_capacity(num_args);
-        for _ in 0..num_args {
-            arg_names.push(self.snappy.read_string()?);
+        for index in 0..num_args {
+            match self.snappy.read_string() {
+                Ok(arg_name) => arg_names.push(arg_name),
+                Err(err) => {
+                    return self.signature_fallback(
+                        id,
+                        vec![sig_frame(), ContextFrame::Field { name: "arg_names", index }],
+                        err,
+                        FunctionSignature { id, name, num_args, arg_names, ..Default::default() },
+                    );
+                }
+            }
         }
         let flag = Call::lookup_call_flag(&name)?;
 
@@ -409,27 +584,59 @@ impl Parser {
         Ok(sig)
     }
 
+    /// Consumes a cached enum signature's declaration when it is read again
+    /// from before the position it was first decoded at.
+    fn skip_enum_sig_refresh(&mut self) -> Result<(), SnappyError> {
+        let num_args = self.snappy.read_varint()?;
+        for _ in 0..num_args {
+            let _ = self.snappy.read_string()?;
+            let _ = self.snappy.read_signed_varint()?;
+        }
+        Ok(())
+    }
+
     fn parse_enum_sig(&mut self) -> Result<Rc<EnumSignature>, ParserError> {
         let id = self.snappy.read_varint()?;
-        let enum_signature = Parser::lookup(&mut self.enums, id);
-        match enum_signature {
-            Some(val) => {
-                if self.snappy.get_current_offset() < *val.state.as_ref().unwrap() {
-                    let num_args = self.snappy.read_varint()?;
-                    for _ in 0..num_args {
-                        let _ = self.snappy.read_string()?;
-                        let _ = self.snappy.read_signed_varint()?;
-                    }
+        let sig_frame = || ContextFrame::Signature { kind: "EnumSignature", id };
+        let cached = Parser::lookup(&mut self.enums, id).clone();
+        if let Some(val) = cached {
+            if self.snappy.get_current_offset() < *val.state.as_ref().unwrap() {
+                if let Err(err) = self.skip_enum_sig_refresh() {
+                    return self.signature_fallback(id, vec![sig_frame()], err, val);
                 }
-                return Ok(Rc::clone(&val));
             }
-            None => {}
+            return Ok(val);
         }
-        let num_values = self.snappy.read_varint()?;
+        let num_values = match self.snappy.read_varint() {
+            Ok(num_values) => num_values,
+            Err(err) => {
+                return self.signature_fallback(id, vec![sig_frame()], err, Rc::new(EnumSignature { id, ..Default::default() }));
+            }
+        };
         let mut enum_values = vec![EnumValue::default(); num_values];
-        for n in &mut enum_values {
-            n.name = self.snappy.read_string()?;
-            n.value = self.snappy.read_signed_varint()?;
+        for (index, n) in enum_values.iter_mut().enumerate() {
+            n.name = match self.snappy.read_string() {
+                Ok(name) => name,
+                Err(err) => {
+                    return self.signature_fallback(
+                        id,
+                        vec![sig_frame(), ContextFrame::Field { name: "values", index }],
+                        err,
+                        Rc::new(EnumSignature { id, num_values, ..Default::default() }),
+                    );
+                }
+            };
+            n.value = match self.snappy.read_signed_varint() {
+                Ok(value) => value,
+                Err(err) => {
+                    return self.signature_fallback(
+                        id,
+                        vec![sig_frame(), ContextFrame::Field { name: "values", index }],
+                        err,
+                        Rc::new(EnumSignature { id, num_values, ..Default::default() }),
+                    );
+                }
+            };
         }
 
         let sig = Rc::new(EnumSignature {
@@ -438,31 +645,63 @@ impl Parser {
             values: enum_values,
             state: Some(self.snappy.get_current_offset()),
         });
-        *enum_signature = Some(Rc::clone(&sig));
+        self.enums[id] = Some(Rc::clone(&sig));
         Ok(sig)
     }
 
+    /// Consumes a cached struct signature's declaration when it is read
+    /// again from before the position it was first decoded at.
+    fn skip_struct_sig_refresh(&mut self) -> Result<(), SnappyError> {
+        let _ = self.snappy.read_string()?;
+        let num_args = self.snappy.read_varint()?;
+        for _ in 0..num_args {
+            let _ = self.snappy.read_string()?;
+        }
+        Ok(())
+    }
+
     fn pase_struct_sig(&mut self) -> Result<Rc<StructSignature>, ParserError> {
         let id = self.snappy.read_varint()?;
-        let struct_signature = Parser::lookup(&mut self.structs, id);
-        match struct_signature {
-            Some(val) => {
-                if self.snappy.get_current_offset() < *val.state.as_ref().unwrap() {
-                    let _ = self.snappy.read_string()?;
-                    let num_args = self.snappy.read_varint()?;
-                    for _ in 0..num_args {
-                        let _ = self.snappy.read_string()?;
-                    }
+        let sig_frame = || ContextFrame::Signature { kind: "StructSignature", id };
+        let cached = Parser::lookup(&mut self.structs, id).clone();
+        if let Some(val) = cached {
+            if self.snappy.get_current_offset() < *val.state.as_ref().unwrap() {
+                if let Err(err) = self.skip_struct_sig_refresh() {
+                    return self.signature_fallback(id, vec![sig_frame()], err, val);
                 }
-                return Ok(Rc::clone(&val));
             }
-            None => {}
+            return Ok(val);
         }
-        let name = self.snappy.read_string()?;
-        let num_members = self.snappy.read_varint()?;
+        let name = match self.snappy.read_string() {
+            Ok(name) => name,
+            Err(err) => {
+                return self.signature_fallback(id, vec![sig_frame()], err, Rc::new(StructSignature { id, ..Default::default() }));
+            }
+        };
+        let num_members = match self.snappy.read_varint() {
+            Ok(num_members) => num_members,
+            Err(err) => {
+                return self.signature_fallback(
+                    id,
+                    vec![sig_frame()],
+                    err,
+                    Rc::new(StructSignature { id, name, ..Default::default() }),
+                );
+            }
+        };
         let mut member_names = Vec::with_capacity(num_members);
-        for _ in 0..num_members {
-            member_names.push(self.snappy.read_string()?);
+        for index in 0..num_members {
+            match self.snappy.read_string() {
+                Ok(member_name) => member_names.push(member_name),
+                Err(err) => {
+                    return self.signature_fallback(
+                        id,
+                        vec![sig_frame(), ContextFrame::Field { name: "member_names", index }],
+                        err,
+                        Rc::new(StructSignature { id, name, num_members, member_names, ..Default::default() }),
+                    );
+                }
+            }
         }
         let sig = Rc::new(StructSignature {
             id,
@@ -477,28 +716,47 @@ impl Parser {
 
     fn parse_bitmask_sig(&mut self) -> Result<Rc<BitmaskSignature>, ParserError> {
         let id = self.snappy.read_varint()?;
-        let struct_signature_cached = Parser::lookup(&mut self.bitmasks, id);
-        match struct_signature_cached {
-            Some(val) => {
-                if self.snappy.get_current_offset() < *val.state.as_ref().unwrap() {
-                    let num_flags = self.snappy.read_varint()?;
-                    for _ in 0..num_flags {
-                        let _ = self.snappy.read_string()?;
-                        let _ = self.snappy.read_varint()?;
-                    }
+        let sig_frame = || ContextFrame::Signature { kind: "BitmaskSignature", id };
+        let cached = Parser::lookup(&mut self.bitmasks, id).clone();
+        if let Some(val) = cached {
+            if self.snappy.get_current_offset() < *val.state.as_ref().unwrap() {
+                if let Err(err) = self.skip_bitmask_sig_refresh() {
+                    return self.signature_fallback(id, vec![sig_frame()], err, val);
                 }
-                return Ok(Rc::clone(&val));
             }
-            None => {}
+            return Ok(val);
         }
-        let num_flags = self.snappy.read_varint()?;
+        let num_flags = match self.snappy.read_varint() {
+            Ok(num_flags) => num_flags,
+            Err(err) => {
+                return self.signature_fallback(id, vec![sig_frame()], err, Rc::new(BitmaskSignature { id, ..Default::default() }));
+            }
+        };
         let mut bitmask_flags = Vec::with_capacity(num_flags);
-        for _ in 0..num_flags {
-            let flag = BitmaskFlag {
-                name: self.snappy.read_string()?,
-                value: self.snappy.read_varint()?,
+        for index in 0..num_flags {
+            let name = match self.snappy.read_string() {
+                Ok(name) => name,
+                Err(err) => {
+                    return self.signature_fallback(
+                        id,
+                        vec![sig_frame(), ContextFrame::Field { name: "bitmask_flags", index }],
+                        err,
+                        Rc::new(BitmaskSignature { id, num_flags, bitmask_flags, ..Default::default() }),
+                    );
+                }
             };
-            bitmask_flags.push(flag);
+            let value = match self.snappy.read_varint() {
+                Ok(value) => value,
+                Err(err) => {
+                    return self.signature_fallback(
+                        id,
+                        vec![sig_frame(), ContextFrame::Field { name: "bitmask_flags", index }],
+                        err,
+                        Rc::new(BitmaskSignature { id, num_flags, bitmask_flags, ..Default::default() }),
+                    );
+                }
+            };
+            bitmask_flags.push(BitmaskFlag { name, value });
         }
         let sig = Rc::new(BitmaskSignature {
             id,
@@ -509,4 +767,110 @@ impl Parser {
         self.bitmasks[id] = Some(Rc::clone(&sig));
         Ok(sig)
     }
+
+    /// Consumes a cached bitmask signature's declaration when it is read
+    /// again from before the position it was first decoded at.
+    fn skip_bitmask_sig_refresh(&mut self) -> Result<(), SnappyError> {
+        let num_flags = self.snappy.read_varint()?;
+        for _ in 0..num_flags {
+            let _ = self.snappy.read_string()?;
+            let _ = self.snappy.read_varint()?;
+        }
+        Ok(())
+    }
+
+    fn parse_backtrace(&mut self) -> Result<Vec<Rc<BacktraceFrame>>, ParserError> {
+        let num_frames = self.snappy.read_varint()?;
+        let mut frames = Vec::with_capacity(num_frames);
+        for _ in 0..num_frames {
+            frames.push(self.parse_backtrace_frame()?);
+        }
+        Ok(frames)
+    }
+
+    fn parse_backtrace_frame(&mut self) -> Result<Rc<BacktraceFrame>, ParserError> {
+        let id = self.snappy.read_varint()?;
+        let frame_cached = Parser::lookup(&mut self.backtrace_frames, id);
+        match frame_cached {
+            Some(val) => {
+                if self.snappy.get_current_offset() < *val.state.as_ref().unwrap() {
+                    self.skip_backtrace_frame_fields()?;
+                }
+                return Ok(Rc::clone(val));
+            }
+            None => {}
+        }
+        let mut frame = BacktraceFrame { id, ..Default::default() };
+        self.read_backtrace_frame_fields(&mut frame)?;
+        frame.state = Some(self.snappy.get_current_offset());
+        let frame = Rc::new(frame);
+        self.backtrace_frames[id] = Some(Rc::clone(&frame));
+        Ok(frame)
+    }
+
+    fn read_backtrace_frame_fields(&mut self, frame: &mut BacktraceFrame) -> Result<(), ParserError> {
+        loop {
+            match self.snappy.read::<u8>() {
+                Err(_) => break,
+                Ok(val) => match val {
+                    n if BacktraceDetail::BacktraceEnd as u8 == n => break,
+                    n if BacktraceDetail::BacktraceModule as u8 == n => {
+                        frame.module = self.snappy.read_string()?;
+                    }
+                    n if BacktraceDetail::BacktraceFunction as u8 == n => {
+                        frame.function = self.snappy.read_string()?;
+                    }
+                    n if BacktraceDetail::BacktraceFilename as u8 == n => {
+                        frame.filename = self.snappy.read_string()?;
+                    }
+                    n if BacktraceDetail::BacktraceLinenumber as u8 == n => {
+                        frame.linenumber = self.snappy.read_varint()?;
+                    }
+                    n if BacktraceDetail::BacktraceOffset as u8 == n => {
+                        frame.offset = self.snappy.read_varint()?;
+                    }
+                    _ => {
+                        if self.lenient {
+                            self.diagnostics.push(format!(
+                                "unknown backtrace detail tag {:#x} at {:?}; ending frame early",
+                                val,
+                                self.snappy.get_current_offset()
+                            ));
+                            break;
+                        }
+                        return Err(ParserError::unknown_call_detail(val));
+                    }
+                },
+            }
+        }
+        Ok(())
+    }
+
+    fn skip_backtrace_frame_fields(&mut self) -> Result<(), ParserError> {
+        let mut frame = BacktraceFrame::default();
+        self.read_backtrace_frame_fields(&mut frame)
+    }
+
+    /// Streams calls one at a time, mapping the clean-EOF sentinel
+    /// (`CallError::NoCallAvailable`) to `None` and propagating real errors
+    /// as `Some(Err(..))`, so callers can write `for call in parser.calls()`.
+    pub fn calls(&mut self) -> Calls<'_> {
+        Calls { parser: self }
+    }
+}
+
+pub struct Calls<'a> {
+    parser: &'a mut Parser,
+}
+
+impl<'a> Iterator for Calls<'a> {
+    type Item = Result<Call, ParserError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.parser.parse_call() {
+            Ok(call) => Some(Ok(call)),
+            Err(ParserError::CallFormingError(_, CallError::NoCallAvailable)) => None,
+            Err(err) => Some(Err(err)),
+        }
+    }
 }
@@ -1,9 +1,10 @@
-use std::cell::RefCell;
 use std::collections::{BTreeMap, HashMap};
 use std::ffi::c_void;
+use std::hash::Hash;
+use std::io::{self, Write};
 use std::ops::{Add, Sub};
 use std::ptr::null_mut;
-use std::sync::{Mutex, Once};
+use std::sync::{Arc, RwLock};
 
 use crate::call::Call;
 use crate::value_structure::{self, Blob, None, Pointer, Value};
@@ -29,6 +30,90 @@ impl Region {
     }
 }
 
+// `buffer` only ever points into the replay's own mapped memory, and every
+// access to it goes through `ReplayState`'s locks, so it's sound to move and
+// share a `Region` across the worker threads `ReplayState` is built for.
+unsafe impl Send for Region {}
+unsafe impl Sync for Region {}
+
+/// Wraps a raw GL object pointer so it can live behind the same
+/// `SyncHandleMap` as everything else; the pointer is only ever dereferenced
+/// on the thread doing the actual GL calls, the same contract the rest of
+/// this FFI boundary already relies on.
+#[derive(Debug, Clone, Copy)]
+struct ObjHandle(*mut c_void);
+
+unsafe impl Send for ObjHandle {}
+unsafe impl Sync for ObjHandle {}
+
+/// One GL object's lifetime, keyed by the same trace-side address
+/// `add_obj`/`del_obj`/`to_obj_pointer` use to key `objects`. Lets
+/// `to_obj_pointer` warn on use-after-free instead of quietly resolving a
+/// stale handle to whatever now lives at that address, and lets a caller
+/// scan for objects that were created but never deleted once the trace is
+/// done.
+#[derive(Debug, Clone, Default)]
+pub struct ObjLifetime {
+    pub created_at: usize,
+    pub deleted_at: Option<usize>,
+    pub last_use: Option<usize>,
+}
+
+/// Generic thread-safe handle table: an `Arc<RwLock<HashMap<..>>>` with a
+/// lookup path that only takes a read lock, so a thread resolving a handle
+/// never blocks another thread declaring a new one for longer than the
+/// insert itself.
+#[derive(Clone)]
+pub struct SyncHandleMap<K, V> {
+    inner: Arc<RwLock<HashMap<K, V>>>,
+}
+
+impl<K, V> SyncHandleMap<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    pub fn new() -> Self {
+        Self { inner: Arc::new(RwLock::new(HashMap::new())) }
+    }
+
+    pub fn get_or_insert(&self, key: K, make: impl FnOnce() -> V) -> V {
+        if let Some(value) = self.inner.read().unwrap().get(&key) {
+            return value.clone();
+        }
+        self.inner.write().unwrap().entry(key).or_insert_with(make).clone()
+    }
+
+    pub fn insert(&self, key: K, value: V) -> Option<V> {
+        self.inner.write().unwrap().insert(key, value)
+    }
+
+    pub fn lookup(&self, key: &K) -> Option<V> {
+        self.inner.read().unwrap().get(key).cloned()
+    }
+
+    pub fn remove(&self, key: &K) -> Option<V> {
+        self.inner.write().unwrap().remove(key)
+    }
+
+    /// A point-in-time copy of every entry, for reporting (e.g. a leak scan
+    /// at end-of-trace) where holding the read lock for the whole walk isn't
+    /// worth it.
+    pub fn snapshot(&self) -> Vec<(K, V)> {
+        self.inner.read().unwrap().iter().map(|(k, v)| (k.clone(), v.clone())).collect()
+    }
+}
+
+impl<K, V> Default for SyncHandleMap<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct Range {
     pub ptr: *mut u8,
@@ -36,124 +121,249 @@ pub struct Range {
     pub dims: u32,
     pub trace_pitch: i32,
     pub real_pitch: i32,
+    /// Backing storage for `ptr` when `Translator` had to repack rows onto a
+    /// different stride (`trace_pitch != real_pitch`) — `None` on the
+    /// zero-copy fast path, where `ptr` points straight into a mapped
+    /// region. Kept alongside `ptr` so the repacked bytes live exactly as
+    /// long as the `Range` that references them.
+    pub staging: Option<Vec<u8>>,
 }
 
-static mut REGION_MAP: Option<RefCell<BTreeMap<usize, Region>>> = None;
-static INIT: Once = Once::new();
-static mut OBJ_MAP: Option<RefCell<HashMap<usize, *mut c_void>>> = None;
+/// Owns all the memory-mapping state a replay needs: the region map, the
+/// object table, and the uniform-location remapping tables. Threading this
+/// explicitly through `Retracer::retrace` (instead of reaching into
+/// `static mut` globals) means two independent replays can run in the same
+/// process without cross-talk, and removes the `unsafe`/
+/// `#[allow(static_mut_refs)]` the old globals required.
+///
+/// Every namespace is reference-counted and interiorly synchronized, so
+/// `ReplayState` is cheap to `Clone` and share across worker threads — e.g.
+/// one thread decoding/prefetching buffer blobs while another walks calls
+/// and submits them to GL. Readers take a read lock and never block each
+/// other or a writer finishing an unrelated insert.
+#[derive(Clone, Default)]
+pub struct ReplayState {
+    regions: Arc<RwLock<BTreeMap<usize, Arc<RwLock<Region>>>>>,
+    objects: SyncHandleMap<usize, ObjHandle>,
+    lifetimes: SyncHandleMap<usize, ObjLifetime>,
+    pub uniforms: Arc<RwLock<Map<i32>>>,
+    pub handles: Arc<RwLock<crate::handles::HandleRemapper>>,
+}
 
-#[allow(static_mut_refs)]
-fn region_map() -> &'static RefCell<BTreeMap<usize, Region>> {
-    unsafe {
-        INIT.call_once(|| {
-            REGION_MAP = Some(RefCell::new(BTreeMap::new()));
-        });
-        REGION_MAP.as_ref().unwrap()
+impl ReplayState {
+    pub fn new() -> Self {
+        Self::default()
     }
-}
 
-#[allow(static_mut_refs)]
-fn obj_map() -> &'static RefCell<HashMap<usize, *mut c_void>> {
-    unsafe {
-        if OBJ_MAP.is_none() {
-            OBJ_MAP = Some(RefCell::new(HashMap::new()));
+    pub fn add_region(&self, address: usize, buffer: *mut u8, size: usize) {
+        if address == 0 {
+            panic!("Expected a pointer got a nullptr");
         }
-        OBJ_MAP.as_ref().unwrap()
+
+        let mut regions = self.regions.write().unwrap();
+
+        let overlaps: Vec<(usize, usize)> = regions
+            .range(..=address + size - 1)
+            .filter(|(k, v)| intersects((*k, &v.read().unwrap()), address, size))
+            .map(|(&k, v)| (k, v.read().unwrap().size))
+            .collect();
+
+        for (addr, existing_size) in &overlaps {
+            if *addr == address && *existing_size == size {
+                // Same range re-registered (e.g. a buffer rebound at the
+                // address it already occupies) — coalesce by letting the
+                // insert below replace it in place, no warning needed.
+                continue;
+            }
+            // Any other overlap would leave two genuinely overlapping
+            // entries in `regions`, breaking the non-overlapping-ranges
+            // invariant `lookup_region_key`/`lookup_address` rely on — that's
+            // a caller bug (double-mapping memory without unmapping the
+            // first range), not something to paper over with a warning.
+            panic!(
+                "new region 0x{:x}-0x{:x} intersects existing 0x{:x}-0x{:x}",
+                address,
+                address + size,
+                addr,
+                addr + existing_size
+            );
+        }
+
+        regions.insert(address, Arc::new(RwLock::new(Region::new(buffer, size))));
     }
-}
 
-fn contains((addr, region): (&usize, &Region), address: usize) -> bool {
-    *addr <= address && (addr + region.size) > address
-}
+    pub fn del_region(&self, address: usize) {
+        assert!(self.regions.write().unwrap().remove(&address).is_some());
+    }
 
-fn intersects((addr, region): (&usize, &Region), start: usize, size: usize) -> bool {
-    let it_start = *addr;
-    let it_stop = it_start + region.size;
-    let stop = start + size;
-    it_start < stop && start < it_stop
-}
+    pub fn del_region_by_pointer(&self, ptr: *mut u8) {
+        let mut regions = self.regions.write().unwrap();
+        let addr = regions
+            .iter()
+            .find_map(|(k, region)| if region.read().unwrap().buffer == ptr { Some(*k) } else { None });
+        assert!(regions.remove(&addr.unwrap()).is_some());
+    }
 
-pub fn add_region(address: usize, buffer: *mut u8, size: usize) {
-    if address == 0 {
-        panic!("Expected a pointer got a nullptr");
+    pub fn set_region_pitch(&self, address: usize, dims: u32, trace_pitch: i32, real_pitch: i32) {
+        let key = self.lookup_region_key(address).expect("Region not found");
+        let regions = self.regions.read().unwrap();
+        let mut region = regions[&key].write().unwrap();
+        region.dimensions = dims;
+        region.trace_pitch = trace_pitch;
+        region.real_pitch = real_pitch;
     }
 
-    let mut map = region_map().borrow_mut();
+    pub fn lookup_region_key(&self, address: usize) -> Option<usize> {
+        let regions = self.regions.read().unwrap();
+        let (&key, region) = regions.range(..=address).next_back()?;
+        let region = region.read().unwrap();
+        if contains((&key, &*region), address) {
+            Some(key)
+        } else {
+            None
+        }
+    }
 
-    // let overlaps: Vec<_> = map
-    //     .range(..=address + size - 1)
-    //     .filter(|(k, _)| intersects((*k, &map[k]), address, size))
-    //     .collect();
+    pub fn lookup_address(&self, address: usize, range: &mut Range) {
+        if let Some(key) = self.lookup_region_key(address) {
+            let regions = self.regions.read().unwrap();
+            let region = regions[&key].read().unwrap();
+            let offset = address - key;
+            assert!(offset < region.size);
+
+            range.ptr = unsafe { region.buffer.add(offset) };
+            range.len = region.size - offset;
+            range.dims = region.dimensions;
+            range.trace_pitch = region.trace_pitch;
+            range.real_pitch = region.real_pitch;
+            return;
+        }
 
-    // for (addr, reg) in overlaps {
-    //     eprintln!(
-    //         "warning: new region 0x{:x}-0x{:x} intersects existing 0x{:x}-0x{:x}",
-    //         address,
-    //         address + size,
-    //         addr,
-    //         addr + reg.size
-    //     );
-    // }
+        range.ptr = address as *mut u8;
+        range.len = 0;
+        range.dims = 0;
+        range.trace_pitch = 0;
+        range.real_pitch = 0;
+    }
 
-    map.insert(address, Region::new(buffer, size));
-}
+    pub fn add_obj(&self, call: &Call, value: &dyn Value, obj: *mut c_void) {
+        let address = value.to_pointer();
 
-pub fn del_region(address: usize) {
-    let mut map = region_map().borrow_mut();
-    assert!(map.remove(&address).is_some());
-}
+        if address == None {
+            if !obj.is_null() {
+                println!("Unexpected non-null object: {:?}", call);
+            }
+            return;
+        } else if let Some(address) = address {
+            if obj.is_null() {
+                println!("Got null for object 0x{:x}", address as usize);
+            }
+            let key = address as usize;
+            self.objects.insert(key, ObjHandle(obj));
+            self.lifetimes.insert(key, ObjLifetime { created_at: call.number, deleted_at: None, last_use: None });
+        }
+    }
 
-pub fn del_region_by_pointer(ptr: *mut u8) {
-    let mut map = region_map().borrow_mut();
-    let addr = map
-        .iter()
-        .find_map(|(k, region)| if region.buffer == ptr { Some(*k) } else { None });
-    assert!(map.remove(&addr.unwrap()).is_some());
-}
+    pub fn del_obj(&self, call: &Call, value: &dyn Value) {
+        let address = value.to_pointer();
+        if let Some(address) = address {
+            let key = address as usize;
+            self.objects.remove(&key);
+            if let Some(mut lifetime) = self.lifetimes.lookup(&key) {
+                lifetime.deleted_at = Some(call.number);
+                self.lifetimes.insert(key, lifetime);
+            }
+        }
+    }
 
-pub fn set_region_pitch(address: usize, dims: u32, trace_pitch: i32, real_pitch: i32) {
-    let mut map = region_map().borrow_mut();
-    let region = map
-        .get_mut(&lookup_region_key(address).expect("Region not found"))
-        .unwrap();
-    region.dimensions = dims;
-    region.trace_pitch = trace_pitch;
-    region.real_pitch = real_pitch;
-}
+    pub fn to_obj_pointer(&self, call: &Call, value: &dyn Value) -> *mut c_void {
+        let address = value.to_pointer();
+
+        if let Some(address) = address {
+            let key = address as usize;
+
+            if let Some(mut lifetime) = self.lifetimes.lookup(&key) {
+                if let Some(deleted_at) = lifetime.deleted_at {
+                    if deleted_at <= call.number {
+                        println!(
+                            "use-after-free: call {} references object 0x{:x}, deleted at call {}",
+                            call.number, address as usize, deleted_at
+                        );
+                    }
+                }
+                lifetime.last_use = Some(call.number);
+                self.lifetimes.insert(key, lifetime);
+            }
+
+            let obj = self.objects.lookup(&key).map(|h| h.0).unwrap_or(std::ptr::null_mut());
 
-pub fn lookup_region_key(address: usize) -> Option<usize> {
-    let map = region_map().borrow_mut();
-    let mut keys: Vec<&usize> = map.keys().collect();
-    keys.sort();
+            if obj.is_null() {
+                println!("unknown object 0x{:x}", address as usize);
+            }
 
-    for &k in keys.iter().rev() {
-        if contains((&k, &map[&k]), address) {
-            return Some(*k);
+            obj
+        } else {
+            std::ptr::null_mut()
         }
     }
-    None
+
+    /// Every tracked object never deleted by the point this is called —
+    /// meant to be run once after the last call has been retraced, to
+    /// report leaks the same way `to_obj_pointer` reports use-after-free as
+    /// it goes.
+    pub fn leaked_objects(&self) -> Vec<(usize, ObjLifetime)> {
+        let mut leaks: Vec<_> = self.lifetimes.snapshot().into_iter().filter(|(_, lifetime)| lifetime.deleted_at.is_none()).collect();
+        leaks.sort_by_key(|(address, _)| *address);
+        leaks
+    }
+
+    /// Renders the current region map and live objects as a GraphViz DOT
+    /// graph: one node per mapped region (labeled with its address range,
+    /// size, and pitch/dimension metadata), one node per live object, and an
+    /// edge from an object to every region whose address range contains it.
+    /// Handy for eyeballing a replay's memory layout with `dot -Tpng`.
+    pub fn dump_dot<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writeln!(writer, "digraph regions {{")?;
+
+        let regions = self.regions.read().unwrap();
+        for (&address, region) in regions.iter() {
+            let region = region.read().unwrap();
+            writeln!(
+                writer,
+                "  region_{:x} [shape=box, label=\"0x{:x}-0x{:x}\\nsize={} dims={}\\ntrace_pitch={} real_pitch={}\"];",
+                address,
+                address,
+                address + region.size,
+                region.size,
+                region.dimensions,
+                region.trace_pitch,
+                region.real_pitch
+            )?;
+        }
+
+        for (address, _) in self.objects.snapshot() {
+            writeln!(writer, "  obj_{:x} [shape=ellipse, label=\"0x{:x}\"];", address, address)?;
+            for (&region_address, region) in regions.iter() {
+                let region = region.read().unwrap();
+                if contains((&region_address, &*region), address) {
+                    writeln!(writer, "  obj_{:x} -> region_{:x};", address, region_address)?;
+                }
+            }
+        }
+
+        writeln!(writer, "}}")
+    }
+}
+
+fn contains((addr, region): (&usize, &Region), address: usize) -> bool {
+    *addr <= address && (addr + region.size) > address
 }
 
-pub fn lookup_address(address: usize, range: &mut Range) {
-    let map = region_map().borrow_mut();
-    if let Some(key) = lookup_region_key(address) {
-        let region = &map[&key];
-        let offset = address - key;
-        assert!(offset < region.size);
-
-        range.ptr = unsafe { region.buffer.add(offset as usize) };
-        range.len = region.size - offset;
-        range.dims = region.dimensions;
-        range.trace_pitch = region.trace_pitch;
-        range.real_pitch = region.real_pitch;
-        return;
-    }
-
-    range.ptr = address as *mut u8;
-    range.len = 0;
-    range.dims = 0;
-    range.trace_pitch = 0;
-    range.real_pitch = 0;
+fn intersects((addr, region): (&usize, &Region), start: usize, size: usize) -> bool {
+    let it_start = *addr;
+    let it_stop = it_start + region.size;
+    let stop = start + size;
+    it_start < stop && start < it_stop
 }
 
 pub struct Translator<'a> {
@@ -174,10 +384,11 @@ impl<'a> Translator<'a> {
         range.dims = 0;
         range.trace_pitch = 0;
         range.real_pitch = 0;
+        range.staging = None;
         Self { bind, range }
     }
 
-    pub fn apply(&mut self, value: Translatable) {
+    pub fn apply(&mut self, state: &ReplayState, value: Translatable) {
         match value {
             Translatable::None(_) => {
                 self.range.ptr = null_mut();
@@ -189,92 +400,97 @@ impl<'a> Translator<'a> {
                 self.range.ptr = blob.to_pointer().unwrap() as *mut u8;
                 self.range.len = blob.size;
                 self.range.dims = 0;
+                self.repack_for_pitch();
             }
             Translatable::Pointer(p) => {
-                lookup_address(p.value as usize, self.range);
+                state.lookup_address(p.value as usize, self.range);
+                self.repack_for_pitch();
             }
         }
     }
+
+    /// A trace can be captured against one row alignment (`trace_pitch`)
+    /// and replayed against another (`real_pitch`) — e.g. the trace's GL
+    /// driver padded rows to 4 bytes and this one pads to 8. Left alone,
+    /// handing the mapped region straight to GL lands every row but the
+    /// first at the wrong offset. When the region carries 2+ dimensions and
+    /// the pitches disagree, repack the rows onto `real_pitch` into a
+    /// staging buffer owned by `range` and point `ptr` at that instead;
+    /// when `dims == 0` (no pitch info, e.g. a flat blob) or the pitches
+    /// already match, this is a no-op and `ptr` keeps pointing straight
+    /// into the mapped region.
+    fn repack_for_pitch(&mut self) {
+        let range = &mut *self.range;
+        if range.dims < 2 || range.ptr.is_null() || range.trace_pitch <= 0 || range.real_pitch <= 0 || range.trace_pitch == range.real_pitch {
+            return;
+        }
+
+        let trace_pitch = range.trace_pitch as usize;
+        let real_pitch = range.real_pitch as usize;
+        let row_bytes = trace_pitch.min(real_pitch);
+        let rows = range.len / trace_pitch;
+        if rows == 0 {
+            return;
+        }
+
+        let mut staging = vec![0u8; rows * real_pitch];
+        for row in 0..rows {
+            let src = unsafe { std::slice::from_raw_parts(range.ptr.add(row * trace_pitch), row_bytes) };
+            staging[row * real_pitch..row * real_pitch + row_bytes].copy_from_slice(src);
+        }
+
+        range.ptr = staging.as_mut_ptr();
+        range.len = staging.len();
+        range.staging = Some(staging);
+    }
 }
 
-pub fn to_range(value: &dyn Value, range: &mut Range) {
+pub fn to_range(state: &ReplayState, value: &dyn Value, range: &mut Range) {
     if let Some(_) = value.as_any().downcast_ref::<value_structure::None>() {
-        Translator::new(false, range).apply(Translatable::None(value_structure::None {}));
+        Translator::new(false, range).apply(state, Translatable::None(value_structure::None {}));
     } else if let Some(pointer_type) = value.as_any().downcast_ref::<value_structure::Pointer>() {
-        Translator::new(false, range).apply(Translatable::Pointer(value_structure::Pointer {
-            value: pointer_type.value,
-        }));
+        Translator::new(false, range).apply(
+            state,
+            Translatable::Pointer(value_structure::Pointer {
+                value: pointer_type.value,
+            }),
+        );
     } else if let Some(blob_type) = value.as_any().downcast_ref::<value_structure::Blob>() {
-        Translator::new(false, range).apply(Translatable::Blob(value_structure::Blob {
-            size: blob_type.size,
-            buffer: blob_type.buffer.clone(),
-            bound: blob_type.bound,
-        }));
+        Translator::new(false, range).apply(
+            state,
+            Translatable::Blob(value_structure::Blob {
+                size: blob_type.size,
+                buffer: blob_type.buffer.clone(),
+                bound: blob_type.bound,
+            }),
+        );
     }
 }
 
-pub fn to_pointer(value: &dyn Value, bind: bool) -> *mut u8 {
+pub fn to_pointer(state: &ReplayState, value: &dyn Value, bind: bool) -> *mut u8 {
     let mut range = Range::default();
     if let Some(_) = value.as_any().downcast_ref::<value_structure::None>() {
-        Translator::new(bind, &mut range).apply(Translatable::None(value_structure::None {}));
+        Translator::new(bind, &mut range).apply(state, Translatable::None(value_structure::None {}));
     } else if let Some(pointer_type) = value.as_any().downcast_ref::<value_structure::Pointer>() {
-        Translator::new(bind, &mut range).apply(Translatable::Pointer(value_structure::Pointer {
-            value: pointer_type.value,
-        }));
+        Translator::new(bind, &mut range).apply(
+            state,
+            Translatable::Pointer(value_structure::Pointer {
+                value: pointer_type.value,
+            }),
+        );
     } else if let Some(blob_type) = value.as_any().downcast_ref::<value_structure::Blob>() {
-        Translator::new(bind, &mut range).apply(Translatable::Blob(value_structure::Blob {
-            size: blob_type.size,
-            buffer: blob_type.buffer.clone(),
-            bound: blob_type.bound,
-        }));
+        Translator::new(bind, &mut range).apply(
+            state,
+            Translatable::Blob(value_structure::Blob {
+                size: blob_type.size,
+                buffer: blob_type.buffer.clone(),
+                bound: blob_type.bound,
+            }),
+        );
     }
     range.ptr
 }
 
-pub fn add_obj(call: &Call, value: &dyn Value, obj: *mut c_void) {
-    let address = value.to_pointer();
-
-    if address == None {
-        if !obj.is_null() {
-            println!("Unexpected non-null object: {:?}", call);
-        }
-        return;
-    } else if let Some(address) = address {
-        if obj.is_null() {
-            println!("Got null for object 0x{:x}", address as usize);
-        }
-        let mut map = obj_map().borrow_mut();
-        map.insert(address as usize, obj);
-    }
-}
-
-pub fn del_obj(value: &dyn Value) {
-    let address = value.to_pointer();
-    if let Some(address) = address {
-        let mut map = obj_map().borrow_mut();
-        map.remove(&(address as usize));
-    }
-}
-
-pub fn to_obj_pointer(call: Call, value: &dyn Value) -> *mut c_void {
-    let address = value.to_pointer();
-
-    let obj = if let Some(address) = address {
-        let map = obj_map().borrow_mut();
-        let obj = *map.get(&(address as usize)).unwrap_or(&std::ptr::null_mut());
-
-        if obj.is_null() {
-           println!("unknown object 0x{:x}", address as usize);
-        }
-
-        obj
-    } else {
-        std::ptr::null_mut()
-    };
-
-    obj
-}
-
 pub fn block_on_fence(call: &Call, sync: gl::types::GLsync, flags: gl::types::GLbitfield) -> gl::types::GLenum {
     let mut result: gl::types::GLenum;
 
@@ -334,6 +550,14 @@ where
         }
     }
 
+    pub fn insert(&mut self, key: T, value: T) -> Option<T> {
+        self.base.insert(key, value)
+    }
+
+    pub fn remove(&mut self, key: &T) -> Option<T> {
+        self.base.remove(key)
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = (&T, &T)> {
         self.base.iter()
     }
@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::panic::Location;
+
+use crate::call::Call;
+use crate::dump;
+use crate::value_structure::Value;
+
+/// A decoded call's arguments bound to their `FunctionSignature::arg_names`,
+/// the same pairing `dump::CallDump` builds for display — handed to a
+/// registered [`Handler`] instead of being formatted straight to a writer.
+pub struct Args<'a> {
+    call: &'a Call,
+}
+
+impl<'a> Args<'a> {
+    pub fn call(&self) -> &Call {
+        self.call
+    }
+
+    pub fn len(&self) -> usize {
+        self.call.args.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.call.args.is_empty()
+    }
+
+    /// The argument bound to `name`, or `None` if the signature has no
+    /// argument by that name.
+    pub fn get(&self, name: &str) -> Option<&dyn Value> {
+        let index = self.call.sig.arg_names.iter().position(|arg_name| arg_name == name)?;
+        self.call.args.get(index).map(|value| value.as_ref())
+    }
+
+    /// Every argument paired with its name, in declaration order. Falls
+    /// back to `"?"` for a missing name the same way `CallDump` does.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &dyn Value)> {
+        self.call.args.iter().enumerate().map(move |(index, value)| {
+            let name = self.call.sig.arg_names.get(index).map(String::as_str).unwrap_or("?");
+            (name, value.as_ref())
+        })
+    }
+}
+
+pub type Handler = fn(&Args);
+
+/// What [`ReplayEngine::dispatch`] does with a call whose `FunctionSignature`
+/// id has no registered handler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnknownPolicy {
+    /// Quietly move on to the next call.
+    Skip,
+    /// Stop playback and report which function couldn't be dispatched.
+    Error,
+}
+
+#[derive(Debug)]
+pub enum ReplayError {
+    UnknownFunction(&'static Location<'static>, usize, String),
+}
+
+impl ReplayError {
+    #[track_caller]
+    fn unknown_function(id: usize, name: String) -> Self {
+        Self::UnknownFunction(Location::caller(), id, name)
+    }
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplayError::UnknownFunction(location, id, name) => write!(
+                f,
+                "ReplayError error: no handler registered for {} (id={}) at {}:{}",
+                name, id, location.file(), location.line()
+            ),
+        }
+    }
+}
+
+impl Error for ReplayError {}
+
+/// Walks a decoded call stream and, for each `Call`, looks up the handler
+/// registered for its `FunctionSignature` (by id, falling back to name the
+/// same way `Retracer::retrace` does) and invokes it with the call's
+/// arguments bound to their declared names.
+pub struct ReplayEngine {
+    by_name: BTreeMap<String, Handler>,
+    by_id: Vec<Option<Handler>>,
+    unknown_policy: UnknownPolicy,
+}
+
+impl ReplayEngine {
+    pub fn new(unknown_policy: UnknownPolicy) -> Self {
+        Self { by_name: BTreeMap::new(), by_id: Vec::new(), unknown_policy }
+    }
+
+    /// A `ReplayEngine` with `trace_handler` pre-registered for `name`, so a
+    /// caller can observe a recording without writing a custom decoder.
+    pub fn with_trace_handler(unknown_policy: UnknownPolicy, name: &str) -> Self {
+        let mut engine = Self::new(unknown_policy);
+        engine.register_handler(name, trace_handler);
+        engine
+    }
+
+    pub fn register_handler(&mut self, name: &str, handler: Handler) {
+        self.by_name.insert(name.to_string(), handler);
+    }
+
+    /// Dispatches a single call to its registered handler, resolving
+    /// `unknown_policy` if none is registered.
+    pub fn dispatch(&mut self, call: &Call) -> Result<(), ReplayError> {
+        let id = call.sig.id;
+        let cached = self.by_id.get(id).copied().flatten();
+        let handler = match cached {
+            Some(handler) => Some(handler),
+            None => {
+                let resolved = self.by_name.get(&call.sig.name).copied();
+                if id >= self.by_id.len() {
+                    self.by_id.resize(id + 1, None);
+                }
+                self.by_id[id] = resolved;
+                resolved
+            }
+        };
+
+        match handler {
+            Some(handler) => {
+                handler(&Args { call });
+                Ok(())
+            }
+            None => match self.unknown_policy {
+                UnknownPolicy::Skip => Ok(()),
+                UnknownPolicy::Error => Err(ReplayError::unknown_function(id, call.sig.name.clone())),
+            },
+        }
+    }
+
+    /// Dispatches every call in the stream in order, stopping at the first
+    /// `Err` (only reachable with `UnknownPolicy::Error`).
+    pub fn replay<'a>(&mut self, calls: impl IntoIterator<Item = &'a Call>) -> Result<(), ReplayError> {
+        for call in calls {
+            self.dispatch(call)?;
+        }
+        Ok(())
+    }
+}
+
+/// Default handler for [`ReplayEngine::with_trace_handler`]: pretty-prints
+/// the call the way `dump::CallDump` does, plus the signature's `state`
+/// stream position, so a recording can be replayed and observed without
+/// writing a custom decoder.
+pub fn trace_handler(args: &Args) {
+    let call = args.call();
+    print!("{} {}(", call.number, call.sig.name);
+    for (index, (name, value)) in args.iter().enumerate() {
+        if index > 0 {
+            print!(", ");
+        }
+        print!("{} = {}", name, dump::format_value(value));
+    }
+    println!(") @ {:?}", call.sig.state);
+}
@@ -1,8 +1,9 @@
 use std::{collections::BTreeMap, error::Error, fmt::Display, panic::Location};
 
 use crate::call::Call;
+use crate::region::ReplayState;
 
-pub type Callback = fn(&mut Call);
+pub type Callback = fn(&mut ReplayState, &mut Call);
 
 struct Entry {
     name: String,
@@ -31,17 +32,44 @@ impl Display for RetracerError {
 
 impl Error for RetracerError {}
 
+/// How a replayed `glGetQueryObject*` callback should treat the query it's
+/// being asked to resolve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryHandling {
+    /// Don't run the query when no query buffer object is bound, since
+    /// there's nowhere for a live result to land anyway; with one bound
+    /// the query still runs.
+    Skip,
+    /// Run the query for real and trust whatever GL returns, no comparison.
+    Run,
+    /// Run the query for real and warn if the live result strays from what
+    /// the trace recorded by more than the configured tolerance.
+    RunAndCheckResult,
+}
+
 pub struct Retracer {
     map: BTreeMap<String, Callback>,
     callbacks: Vec<Option<Callback>>,
+    pub query_handling: QueryHandling,
+    pub query_tolerance: i64,
 }
 
 impl Retracer {
     pub fn init() -> Self {
-        Self { map: BTreeMap::new(), callbacks: Vec::new() }
+        Self {
+            map: BTreeMap::new(),
+            callbacks: Vec::new(),
+            query_handling: QueryHandling::RunAndCheckResult,
+            query_tolerance: 0,
+        }
+    }
+
+    pub fn set_query_handling(&mut self, handling: QueryHandling, tolerance: i64) {
+        self.query_handling = handling;
+        self.query_tolerance = tolerance;
     }
 
-    pub fn retrace(&mut self, call: &mut Call) -> Result<(), RetracerError>{
+    pub fn retrace(&mut self, state: &mut ReplayState, call: &mut Call) -> Result<(), RetracerError>{
         let mut callback: Option<Callback> = None;
         let id = call.sig.id;
         if id >= self.callbacks.len() {
@@ -56,7 +84,7 @@ impl Retracer {
             self.callbacks[id] = callback;
         }
         if let Some(callback) = callback {
-            callback(call);
+            callback(state, call);
             Ok(())
         }
         else {
@@ -69,3 +97,59 @@ impl Retracer {
     }
 
 }
+
+const QUERY_POLL_LIMIT: u32 = 10_000;
+
+/// Shared by every `glGetQueryObject*` callback: resolves what the query
+/// should report for `pname`, honoring `handling`.
+///
+/// - `Skip` with no query buffer object bound returns the trace-recorded
+///   `expected` value untouched, since there's nowhere for a live result to
+///   go anyway.
+/// - `Run`/`RunAndCheckResult` (or `Skip` with a query buffer object bound,
+///   where the real driver has to produce *something*) run the query for
+///   real. Polling `QUERY_RESULT_AVAILABLE` for a query the trace recorded
+///   as ready spins the same way `block_on_fence` waits out
+///   `TIMEOUT_EXPIRED`, since a not-yet-available result here would hand the
+///   caller a `0` where the trace expects a `1` — but unlike
+///   `ClientWaitSync`, `GetQueryObjectiv` has no blocking primitive to wait
+///   on, so the poll yields the thread between attempts and gives up after
+///   `QUERY_POLL_LIMIT` iterations instead of spinning forever on a query
+///   that never becomes available.
+/// - `RunAndCheckResult` additionally warns when the live result and
+///   `expected` disagree by more than `tolerance`.
+pub fn retrace_query_result(
+    handling: QueryHandling,
+    tolerance: i64,
+    query_buffer_bound: bool,
+    query: gl::types::GLuint,
+    pname: gl::types::GLenum,
+    expected: i64,
+) -> i64 {
+    if handling == QueryHandling::Skip && !query_buffer_bound {
+        return expected;
+    }
+
+    let mut actual: gl::types::GLint = 0;
+    for attempt in 0.. {
+        unsafe { gl::GetQueryObjectiv(query, pname, &mut actual) };
+        if pname != gl::QUERY_RESULT_AVAILABLE || expected != 1 || actual != 0 {
+            break;
+        }
+        if attempt >= QUERY_POLL_LIMIT {
+            println!("warning: query {} never became available after {} polls, giving up", query, QUERY_POLL_LIMIT);
+            break;
+        }
+        std::thread::yield_now();
+    }
+    let actual = actual as i64;
+
+    if handling == QueryHandling::RunAndCheckResult && (expected - actual).abs() > tolerance {
+        println!(
+            "warning: query {} result {} differs from trace-recorded {} by more than tolerance {}",
+            query, actual, expected, tolerance
+        );
+    }
+
+    actual
+}
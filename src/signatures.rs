@@ -1,42 +1,90 @@
-use std::{error::Error, panic::Location};
+use std::{collections::HashMap, error::Error, panic::Location, rc::Rc};
 
 use crate::{file, parser};
 
+/// One frame in the breadcrumb trail `signature_fallback` pushes as it
+/// descends into a signature's variable-length fields, nom/winnow
+/// `ContextError`-style: which signature (and id) was being decoded, or
+/// which field/index within it failed. Unwound in `FunctionSignatureError`'s
+/// `Display` so a truncated table reports e.g. "StructSignature id=12 ->
+/// member_names[3]" instead of just the snappy offset that failed.
+#[derive(Debug, Clone)]
+pub enum ContextFrame {
+    Signature { kind: &'static str, id: usize },
+    Field { name: &'static str, index: usize },
+}
+
+impl std::fmt::Display for ContextFrame {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ContextFrame::Signature { kind, id } => write!(f, "{} id={}", kind, id),
+            ContextFrame::Field { name, index } => write!(f, "{}[{}]", name, index),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum FunctionSignatureError {
-    ParserError(&'static Location<'static>, parser::ParserError),
-    SnappyError(&'static Location<'static>, file::SnappyError),
+    ParserError(&'static Location<'static>, parser::ParserError, Vec<ContextFrame>),
+    SnappyError(&'static Location<'static>, file::SnappyError, Vec<ContextFrame>),
 }
 
 impl std::fmt::Display for FunctionSignatureError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            FunctionSignatureError::ParserError(loc, err) => {
-                write!(f, "Parser error: {} at {}:{}", err, loc.file(), loc.line())
+            FunctionSignatureError::ParserError(loc, err, context) => {
+                write!(f, "Parser error: {} at {}:{}", err, loc.file(), loc.line())?;
+                write_context(f, context)
             }
-            FunctionSignatureError::SnappyError(loc, err) => {
-                write!(f, "Snappy error: {} at {}:{}", err, loc.file(), loc.line())
+            FunctionSignatureError::SnappyError(loc, err, context) => {
+                write!(f, "Snappy error: {} at {}:{}", err, loc.file(), loc.line())?;
+                write_context(f, context)
             }
         }
     }
 }
 
+fn write_context(f: &mut std::fmt::Formatter, context: &[ContextFrame]) -> std::fmt::Result {
+    if context.is_empty() {
+        return Ok(());
+    }
+    write!(f, " (while reading ")?;
+    for (index, frame) in context.iter().enumerate() {
+        if index > 0 {
+            write!(f, " -> ")?;
+        }
+        write!(f, "{}", frame)?;
+    }
+    write!(f, ")")
+}
+
 impl FunctionSignatureError {
     #[track_caller]
     fn parser_error(error: parser::ParserError) -> Self {
-        Self::ParserError(Location::caller(), error)
+        Self::ParserError(Location::caller(), error, Vec::new())
     }
     #[track_caller]
     fn snappy_error(error: file::SnappyError) -> Self {
-        Self::SnappyError(Location::caller(), error)
+        Self::SnappyError(Location::caller(), error, Vec::new())
+    }
+
+    /// Pushes a breadcrumb frame onto this error's context trail. Called by
+    /// `signature_fallback` outermost-frame-first, so the trail prints in
+    /// descent order (signature, then the field/index within it).
+    pub(crate) fn with_context(mut self, frame: ContextFrame) -> Self {
+        match &mut self {
+            FunctionSignatureError::ParserError(_, _, context) => context.push(frame),
+            FunctionSignatureError::SnappyError(_, _, context) => context.push(frame),
+        }
+        self
     }
 }
 
 impl Error for FunctionSignatureError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
-            FunctionSignatureError::ParserError(_, parser_error) => Some(parser_error),
-            FunctionSignatureError::SnappyError(_, snappy_error) => Some(snappy_error),
+            FunctionSignatureError::ParserError(_, parser_error, _) => Some(parser_error),
+            FunctionSignatureError::SnappyError(_, snappy_error, _) => Some(snappy_error),
         }
     }
 }
@@ -53,6 +101,89 @@ impl From<file::SnappyError> for FunctionSignatureError {
     }
 }
 
+/// A `FunctionSignatureError` tagged with the id of the signature that
+/// failed to parse, so a caller sifting through an `ErrorStack` can tell
+/// which `FunctionSignature`/`EnumSignature`/`StructSignature`/
+/// `BitmaskSignature` it came from.
+#[derive(Debug)]
+pub struct SignatureError {
+    pub id: usize,
+    pub error: FunctionSignatureError,
+}
+
+impl std::fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "signature #{}: {}", self.id, self.error)
+    }
+}
+
+impl Error for SignatureError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// An OpenSSL `ErrorStack`-style collector for signature parse failures.
+/// In strict mode (what `Parser::new` starts with, matching the old
+/// fail-fast behavior; `ErrorStack::default()` is not strict)
+/// [`ErrorStack::record`] returns the first error it's given instead of
+/// storing it, so a bad `FunctionSignature` still aborts the whole parse.
+/// In non-strict mode it records every failure it's handed and lets the
+/// caller carry on to the next signature, so a corrupt trace can be
+/// diagnosed in one pass instead of fix-and-rerun.
+#[derive(Debug, Default)]
+pub struct ErrorStack {
+    errors: Vec<SignatureError>,
+    strict: bool,
+}
+
+impl ErrorStack {
+    pub fn new(strict: bool) -> Self {
+        Self { errors: Vec::new(), strict }
+    }
+
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Records a failure. Returns `Err` with the same failure back when in
+    /// strict mode, for a caller that wants to bail immediately; otherwise
+    /// stores it and returns `Ok(())` so the caller can substitute a
+    /// fallback and keep going.
+    pub fn record(&mut self, id: usize, error: FunctionSignatureError) -> Result<(), SignatureError> {
+        let error = SignatureError { id, error };
+        if self.strict {
+            return Err(error);
+        }
+        self.errors.push(error);
+        Ok(())
+    }
+
+    pub fn errors(&self) -> &[SignatureError] {
+        &self.errors
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl std::fmt::Display for ErrorStack {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for (index, error) in self.errors.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", error)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FunctionSignature {
     pub id: usize,
@@ -77,6 +208,42 @@ pub(crate) struct EnumSignature {
     pub state: Option<file::Position>,
 }
 
+impl EnumSignature {
+    /// The symbolic name for a wire value, e.g. what `dump::format_enum`
+    /// uses before falling back to hex. When a value has more than one
+    /// name (aliased constants, like `GL_TRUE`/`GL_ONE`), the first
+    /// declared name wins.
+    pub fn name_for_value(&self, value: i64) -> Option<&str> {
+        self.values.iter().find(|v| v.value == value).map(|v| v.name.as_str())
+    }
+
+    /// The wire value for a symbolic name, for encoding a call back into
+    /// its raw integer form.
+    pub fn value_for_name(&self, name: &str) -> Option<i64> {
+        self.values.iter().find(|v| v.name == name).map(|v| v.value)
+    }
+
+    /// A `value -> name` table for bulk lookups. Duplicate values keep
+    /// their first declared name, matching [`EnumSignature::name_for_value`].
+    pub fn name_table(&self) -> HashMap<i64, &str> {
+        let mut table = HashMap::with_capacity(self.values.len());
+        for v in &self.values {
+            table.entry(v.value).or_insert(v.name.as_str());
+        }
+        table
+    }
+
+    /// A `name -> value` table for bulk lookups. Duplicate names keep
+    /// their first declared value, matching [`EnumSignature::value_for_name`].
+    pub fn value_table(&self) -> HashMap<&str, i64> {
+        let mut table = HashMap::with_capacity(self.values.len());
+        for v in &self.values {
+            table.entry(v.name.as_str()).or_insert(v.value);
+        }
+        table
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub(crate) struct StructSignature {
     pub id: usize,
@@ -98,4 +265,118 @@ pub(crate) struct BitmaskSignature {
     pub num_flags: usize,
     pub bitmask_flags: Vec<BitmaskFlag>,
     pub state: Option<file::Position>,
+}
+
+impl BitmaskSignature {
+    /// Every declared flag whose bits are all set in `raw`, in declaration
+    /// order. Flags can overlap (e.g. a wide alias covering several
+    /// narrower ones), so more than one entry can match the same bit —
+    /// callers that want a minimal, mutually-exclusive cover (like
+    /// `dump::format_bitmask`) need to sort and subtract themselves.
+    pub fn decode(&self, raw: usize) -> Vec<&BitmaskFlag> {
+        self.bitmask_flags.iter().filter(|flag| flag.value != 0 && raw & flag.value == flag.value).collect()
+    }
+
+    /// ORs together the values of the named flags. Returns `None` if any
+    /// name isn't declared on this signature, so a caller can tell
+    /// "unknown flag name" apart from "flags that happen to OR to 0".
+    pub fn encode(&self, names: &[&str]) -> Option<usize> {
+        names.iter().try_fold(0usize, |acc, name| {
+            self.bitmask_flags.iter().find(|flag| flag.name == *name).map(|flag| acc | flag.value)
+        })
+    }
+
+    /// Bits set in `raw` that aren't covered by any declared flag — bits a
+    /// newer trace might use that this (older) signature doesn't know
+    /// about yet.
+    pub fn unknown_bits(&self, raw: usize) -> usize {
+        let covered = self.bitmask_flags.iter().fold(0usize, |acc, flag| acc | flag.value);
+        raw & !covered
+    }
+
+    /// Every declared flag paired with whether its bits are all set in
+    /// `raw`.
+    pub fn bits(&self, raw: usize) -> impl Iterator<Item = (&BitmaskFlag, bool)> {
+        self.bitmask_flags.iter().map(move |flag| (flag, flag.value != 0 && raw & flag.value == flag.value))
+    }
+}
+
+/// A single resolved stack frame from a `CallDetail::CallBacktrace` record,
+/// dedup-cached by `id` the same way function/enum/struct signatures are.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct BacktraceFrame {
+    pub id: usize,
+    pub module: String,
+    pub function: String,
+    pub filename: String,
+    pub linenumber: usize,
+    pub offset: usize,
+    pub state: Option<file::Position>,
+}
+
+/// A navigable snapshot of every signature a `Parser` has decoded so far,
+/// keyed the same way the parser's own internal caches are (`id` as the
+/// index) plus by-name lookup for functions and structs. Built with
+/// `Parser::signature_registry`; this turns the parser's flat, write-only
+/// per-id caches into a read-only schema/symbol table for downstream
+/// tooling (dumping, replay, diffing two traces' signatures, ...).
+#[derive(Debug, Default)]
+pub(crate) struct SignatureRegistry {
+    functions: Vec<Option<FunctionSignature>>,
+    functions_by_name: HashMap<String, usize>,
+    enums: Vec<Option<Rc<EnumSignature>>>,
+    structs: Vec<Option<Rc<StructSignature>>>,
+    structs_by_name: HashMap<String, usize>,
+    bitmasks: Vec<Option<Rc<BitmaskSignature>>>,
+}
+
+impl SignatureRegistry {
+    pub(crate) fn new(
+        functions: Vec<Option<FunctionSignature>>,
+        enums: Vec<Option<Rc<EnumSignature>>>,
+        structs: Vec<Option<Rc<StructSignature>>>,
+        bitmasks: Vec<Option<Rc<BitmaskSignature>>>,
+    ) -> Self {
+        let functions_by_name = functions
+            .iter()
+            .enumerate()
+            .filter_map(|(id, sig)| sig.as_ref().map(|sig| (sig.name.clone(), id)))
+            .collect();
+        let structs_by_name = structs
+            .iter()
+            .enumerate()
+            .filter_map(|(id, sig)| sig.as_ref().map(|sig| (sig.name.clone(), id)))
+            .collect();
+        Self { functions, functions_by_name, enums, structs, structs_by_name, bitmasks }
+    }
+
+    pub fn function_by_id(&self, id: usize) -> Option<&FunctionSignature> {
+        self.functions.get(id)?.as_ref()
+    }
+
+    pub fn function_by_name(&self, name: &str) -> Option<&FunctionSignature> {
+        self.function_by_id(*self.functions_by_name.get(name)?)
+    }
+
+    pub fn enum_by_id(&self, id: usize) -> Option<&Rc<EnumSignature>> {
+        self.enums.get(id)?.as_ref()
+    }
+
+    pub fn struct_by_id(&self, id: usize) -> Option<&Rc<StructSignature>> {
+        self.structs.get(id)?.as_ref()
+    }
+
+    pub fn struct_by_name(&self, name: &str) -> Option<&Rc<StructSignature>> {
+        self.struct_by_id(*self.structs_by_name.get(name)?)
+    }
+
+    /// Shorthand for `struct_by_name(name).map(|sig| &sig.member_names)`,
+    /// for callers that just want the field list.
+    pub fn struct_members(&self, name: &str) -> Option<&[String]> {
+        self.struct_by_name(name).map(|sig| sig.member_names.as_slice())
+    }
+
+    pub fn bitmask_by_id(&self, id: usize) -> Option<&Rc<BitmaskSignature>> {
+        self.bitmasks.get(id)?.as_ref()
+    }
 }
\ No newline at end of file
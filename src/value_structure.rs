@@ -267,6 +267,79 @@ impl Value for VString {
     }
 }
 
+#[derive(Debug)]
+pub struct WString {
+    pub value: String,
+}
+
+impl Value for WString {
+    fn to_bool(&self) -> Option<bool> {
+        Some(true)
+    }
+    fn to_u32(&self) -> Option<u32> {
+        None
+    }
+    fn to_f32(&self) -> Option<f32> {
+        None
+    }
+    fn to_f64(&self) -> Option<f64> {
+        None
+    }
+    fn to_i32(&self) -> Option<i32> {
+        None
+    }
+    fn to_array(&self) -> Option<&Array> {
+        todo!()
+    }
+
+    fn to_pointer(&self) -> Option<*mut c_void> {
+        todo!()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Wraps a value that carries both a human-readable representation (e.g. a
+/// shader source dump) and the underlying machine value it stands in for.
+/// Numeric/pointer conversions defer to `machine`; `human` is there purely
+/// for display.
+#[derive(Debug)]
+pub struct Repr {
+    pub human: Box<dyn Value>,
+    pub machine: Box<dyn Value>,
+}
+
+impl Value for Repr {
+    fn to_bool(&self) -> Option<bool> {
+        self.machine.to_bool()
+    }
+    fn to_u32(&self) -> Option<u32> {
+        self.machine.to_u32()
+    }
+    fn to_i32(&self) -> Option<i32> {
+        self.machine.to_i32()
+    }
+    fn to_f32(&self) -> Option<f32> {
+        self.machine.to_f32()
+    }
+    fn to_f64(&self) -> Option<f64> {
+        self.machine.to_f64()
+    }
+    fn to_array(&self) -> Option<&Array> {
+        self.machine.to_array()
+    }
+
+    fn to_pointer(&self) -> Option<*mut c_void> {
+        self.machine.to_pointer()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 #[derive(Debug)]
 pub struct Pointer {
     pub value: *mut c_void,
@@ -308,26 +381,26 @@ pub struct Array {
 
 impl Value for Array {
     fn to_bool(&self) -> Option<bool> {
-        todo!()
+        self.single().and_then(Value::to_bool)
     }
     fn to_u32(&self) -> Option<u32> {
-        todo!()
+        self.single().and_then(Value::to_u32)
     }
     fn to_f32(&self) -> Option<f32> {
-        todo!()
+        self.single().and_then(Value::to_f32)
     }
     fn to_f64(&self) -> Option<f64> {
-        todo!()
+        self.single().and_then(Value::to_f64)
     }
     fn to_i32(&self) -> Option<i32> {
-        todo!()
+        self.single().and_then(Value::to_i32)
     }
     fn to_array(&self) -> Option<&Array> {
         Some(self)
     }
 
     fn to_pointer(&self) -> Option<*mut c_void> {
-        todo!()
+        self.single().and_then(Value::to_pointer)
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -336,9 +409,22 @@ impl Value for Array {
 }
 
 impl Array {
-    fn size(&self) -> usize {
+    pub fn size(&self) -> usize {
         self.values.len()
     }
+
+    pub fn get(&self, index: usize) -> Option<&dyn Value> {
+        self.values.get(index).map(|value| value.as_ref())
+    }
+
+    /// The lone element when this array has exactly one, for scalar
+    /// conversions (`to_u32`, `to_bool`, ...) to delegate to.
+    fn single(&self) -> Option<&dyn Value> {
+        match self.values.as_slice() {
+            [value] => Some(value.as_ref()),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -349,26 +435,26 @@ pub struct Enum {
 
 impl Value for Enum {
     fn to_bool(&self) -> Option<bool> {
-        todo!()
+        Some(self.value != 0)
     }
     fn to_u32(&self) -> Option<u32> {
-        todo!()
+        Some(self.value as u32)
     }
     fn to_f32(&self) -> Option<f32> {
-        todo!()
+        Some(self.value as f32)
     }
     fn to_f64(&self) -> Option<f64> {
-        todo!()
+        Some(self.value as f64)
     }
     fn to_i32(&self) -> Option<i32> {
-        todo!()
+        Some(self.value as i32)
     }
     fn to_array(&self) -> Option<&Array> {
-        todo!()
+        None
     }
 
     fn to_pointer(&self) -> Option<*mut c_void> {
-        todo!()
+        None
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -384,26 +470,26 @@ pub struct Struct {
 
 impl Value for Struct {
     fn to_bool(&self) -> Option<bool> {
-        todo!()
+        None
     }
     fn to_u32(&self) -> Option<u32> {
-        todo!()
+        None
     }
     fn to_f32(&self) -> Option<f32> {
-        todo!()
+        None
     }
     fn to_f64(&self) -> Option<f64> {
-        todo!()
+        None
     }
     fn to_i32(&self) -> Option<i32> {
-        todo!()
+        None
     }
     fn to_array(&self) -> Option<&Array> {
-        todo!()
+        None
     }
 
     fn to_pointer(&self) -> Option<*mut c_void> {
-        todo!()
+        None
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -411,6 +497,15 @@ impl Value for Struct {
     }
 }
 
+impl Struct {
+    /// Resolves a member by name through `StructSignature`, so the retracer
+    /// can bind GL arguments structurally instead of by position.
+    pub fn member(&self, name: &str) -> Option<&dyn Value> {
+        let index = self.sig.member_names.iter().position(|member_name| member_name == name)?;
+        self.members.get(index).map(|value| value.as_ref())
+    }
+}
+
 #[derive(Debug)]
 pub struct Bitmask {
     pub sig: Rc<signatures::BitmaskSignature>,
@@ -419,26 +514,26 @@ pub struct Bitmask {
 
 impl Value for Bitmask {
     fn to_bool(&self) -> Option<bool> {
-        todo!()
+        Some(self.value != 0)
     }
     fn to_u32(&self) -> Option<u32> {
-        todo!()
+        Some(self.value as u32)
     }
     fn to_f32(&self) -> Option<f32> {
-        todo!()
+        Some(self.value as f32)
     }
     fn to_f64(&self) -> Option<f64> {
-        todo!()
+        Some(self.value as f64)
     }
     fn to_i32(&self) -> Option<i32> {
-        todo!()
+        Some(self.value as i32)
     }
     fn to_array(&self) -> Option<&Array> {
-        todo!()
+        None
     }
 
     fn to_pointer(&self) -> Option<*mut c_void> {
-        todo!()
+        None
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -455,22 +550,22 @@ pub struct Blob {
 
 impl Value for Blob {
     fn to_bool(&self) -> Option<bool> {
-        todo!()
+        Some(self.size != 0)
     }
     fn to_u32(&self) -> Option<u32> {
-        Some(6)
+        Some(self.size as u32)
     }
     fn to_f32(&self) -> Option<f32> {
-        todo!()
+        Some(self.size as f32)
     }
     fn to_f64(&self) -> Option<f64> {
-        todo!()
+        Some(self.size as f64)
     }
     fn to_i32(&self) -> Option<i32> {
-        todo!()
+        Some(self.size as i32)
     }
     fn to_array(&self) -> Option<&Array> {
-        todo!()
+        None
     }
 
     fn to_pointer(&self) -> Option<*mut c_void> {
@@ -0,0 +1,285 @@
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    panic::Location,
+};
+
+use crate::{
+    call::{Call, CallDetail, CallError},
+    file::{SnappyError, SnappyWriter},
+    signatures::FunctionSignature,
+    trace::{self, Event},
+    value_structure::Value,
+};
+
+#[derive(Debug)]
+pub enum EncoderError {
+    SnappyError(&'static Location<'static>, SnappyError),
+    UnsupportedValue(&'static Location<'static>, CallError),
+}
+
+impl EncoderError {
+    #[track_caller]
+    pub fn snappy_error(error: SnappyError) -> Self {
+        Self::SnappyError(Location::caller(), error)
+    }
+
+    #[track_caller]
+    pub fn unsupported_value(error: CallError) -> Self {
+        Self::UnsupportedValue(Location::caller(), error)
+    }
+}
+
+impl From<SnappyError> for EncoderError {
+    #[track_caller]
+    fn from(value: SnappyError) -> Self {
+        Self::snappy_error(value)
+    }
+}
+
+impl std::fmt::Display for EncoderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            EncoderError::SnappyError(location, err) => {
+                write!(f, "Snappy error: {} at {}:{}", err, location.file(), location.line())
+            }
+            EncoderError::UnsupportedValue(location, err) => {
+                write!(f, "Unsupported value: {} at {}:{}", err, location.file(), location.line())
+            }
+        }
+    }
+}
+
+impl Error for EncoderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            EncoderError::SnappyError(_, err) => Some(err),
+            EncoderError::UnsupportedValue(_, err) => Some(err),
+        }
+    }
+}
+
+/// Mirrors `Parser`, but in the write direction: re-serializes `Call`s, the
+/// `*Signature` types and `value_structure::Value` into a valid version-6
+/// snappy-compressed trace, so that `parse(write(parse(f))) == parse(f)`.
+pub struct Encoder {
+    pub snappy: SnappyWriter,
+    emitted_functions: HashSet<usize>,
+    emitted_enums: HashSet<usize>,
+    emitted_structs: HashSet<usize>,
+    emitted_bitmasks: HashSet<usize>,
+    emitted_backtrace_frames: HashSet<usize>,
+}
+
+impl Encoder {
+    const TRACE_VERSION: usize = 6;
+
+    pub fn new(path: &str) -> Result<Self, EncoderError> {
+        let mut snappy = SnappyWriter::create(path)?;
+        snappy.write_varint(Self::TRACE_VERSION)?;
+        snappy.write_varint(Self::TRACE_VERSION)?;
+        Ok(Self {
+            snappy,
+            emitted_functions: HashSet::new(),
+            emitted_enums: HashSet::new(),
+            emitted_structs: HashSet::new(),
+            emitted_bitmasks: HashSet::new(),
+            emitted_backtrace_frames: HashSet::new(),
+        })
+    }
+
+    /// Writes the property list followed by the empty-string terminator
+    /// `Parser::parse_properties` stops at. Call once, before the first
+    /// `write_call`, even with no properties.
+    pub fn write_properties(&mut self, properties: &HashMap<String, String>) -> Result<(), EncoderError> {
+        for (name, value) in properties {
+            self.snappy.write_string(name)?;
+            self.snappy.write_string(value)?;
+        }
+        self.snappy.write_string("")?;
+        Ok(())
+    }
+
+    /// Writes a `Call` as the matching `EventEnter`/`EventLeave` pair
+    /// `Parser::parse_call` expects: arguments are attached to the enter
+    /// event, the return value and flags to the leave event.
+    pub fn write_call(&mut self, call: &Call) -> Result<(), EncoderError> {
+        self.snappy.write_type(&(Event::EventEnter as u8))?;
+        self.snappy.write_varint(call.thread_id as usize)?;
+        self.write_function_sig(&call.sig)?;
+        for (index, arg) in call.args.iter().enumerate() {
+            self.snappy.write_type(&(CallDetail::CallArg as u8))?;
+            self.snappy.write_varint(index)?;
+            self.write_value(arg.as_ref())?;
+        }
+        self.snappy.write_type(&(CallDetail::CallEnd as u8))?;
+
+        self.snappy.write_type(&(Event::EventLeave as u8))?;
+        self.snappy.write_varint(call.number)?;
+        if !call.backtrace.is_empty() {
+            self.snappy.write_type(&(CallDetail::CallBacktrace as u8))?;
+            self.write_backtrace(&call.backtrace)?;
+        }
+        if let Some(ret) = &call.ret {
+            self.snappy.write_type(&(CallDetail::CallRet as u8))?;
+            self.write_value(ret.as_ref())?;
+        }
+        if let Some(flag) = call.sig.flag {
+            if flag != 0 {
+                self.snappy.write_type(&(CallDetail::CallFlags as u8))?;
+                self.snappy.write_varint(flag as usize)?;
+            }
+        }
+        self.snappy.write_type(&(CallDetail::CallEnd as u8))?;
+        Ok(())
+    }
+
+    pub fn finish(self) -> Result<(), EncoderError> {
+        Ok(self.snappy.finish()?)
+    }
+
+    fn write_function_sig(&mut self, sig: &FunctionSignature) -> Result<(), EncoderError> {
+        self.snappy.write_varint(sig.id)?;
+        if self.emitted_functions.insert(sig.id) {
+            self.snappy.write_string(&sig.name)?;
+            self.snappy.write_varint(sig.num_args)?;
+            for name in &sig.arg_names {
+                self.snappy.write_string(name)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_value(&mut self, value: &dyn Value) -> Result<(), EncoderError> {
+        use crate::value_structure;
+
+        let any = value.as_any();
+        if any.downcast_ref::<value_structure::None>().is_some() {
+            self.snappy.write_type(&(trace::Type::TypeNull as u8))?;
+        } else if let Some(v) = any.downcast_ref::<value_structure::Bool>() {
+            let tag = if v.value { trace::Type::TypeTrue } else { trace::Type::TypeFalse };
+            self.snappy.write_type(&(tag as u8))?;
+        } else if let Some(v) = any.downcast_ref::<value_structure::I32>() {
+            self.snappy.write_type(&(trace::Type::TypeSint as u8))?;
+            self.snappy.write_varint((-v.value) as usize)?;
+        } else if let Some(v) = any.downcast_ref::<value_structure::U32>() {
+            self.snappy.write_type(&(trace::Type::TypeUint as u8))?;
+            self.snappy.write_varint(v.value as usize)?;
+        } else if let Some(v) = any.downcast_ref::<value_structure::Float>() {
+            self.snappy.write_type(&(trace::Type::TypeFloat as u8))?;
+            self.snappy.write_type(&v.value)?;
+        } else if let Some(v) = any.downcast_ref::<value_structure::Double>() {
+            self.snappy.write_type(&(trace::Type::TypeDouble as u8))?;
+            self.snappy.write_type(&v.value)?;
+        } else if let Some(v) = any.downcast_ref::<value_structure::VString>() {
+            self.snappy.write_type(&(trace::Type::TypeString as u8))?;
+            self.snappy.write_string(&v.value)?;
+        } else if let Some(v) = any.downcast_ref::<value_structure::WString>() {
+            self.snappy.write_type(&(trace::Type::TypeWstring as u8))?;
+            let units: Vec<u16> = v.value.encode_utf16().collect();
+            self.snappy.write_varint(units.len())?;
+            for unit in units {
+                self.snappy.write_type(&unit)?;
+            }
+        } else if let Some(v) = any.downcast_ref::<value_structure::Repr>() {
+            self.snappy.write_type(&(trace::Type::TypeRepr as u8))?;
+            self.write_value(v.human.as_ref())?;
+            self.write_value(v.machine.as_ref())?;
+        } else if let Some(v) = any.downcast_ref::<value_structure::Enum>() {
+            self.snappy.write_type(&(trace::Type::TypeEnum as u8))?;
+            self.write_enum_sig(&v.sig)?;
+            self.snappy.write_signed_varint(v.value)?;
+        } else if let Some(v) = any.downcast_ref::<value_structure::Bitmask>() {
+            self.snappy.write_type(&(trace::Type::TypeBitmask as u8))?;
+            self.write_bitmask_sig(&v.sig)?;
+            self.snappy.write_varint(v.value)?;
+        } else if let Some(v) = any.downcast_ref::<value_structure::Array>() {
+            self.snappy.write_type(&(trace::Type::TypeArray as u8))?;
+            self.snappy.write_varint(v.values.len())?;
+            for elem in &v.values {
+                self.write_value(elem.as_ref())?;
+            }
+        } else if let Some(v) = any.downcast_ref::<value_structure::Struct>() {
+            self.snappy.write_type(&(trace::Type::TypeStruct as u8))?;
+            self.write_struct_sig(&v.sig)?;
+            for member in &v.members {
+                self.write_value(member.as_ref())?;
+            }
+        } else if let Some(v) = any.downcast_ref::<value_structure::Blob>() {
+            self.snappy.write_type(&(trace::Type::TypeBlob as u8))?;
+            self.snappy.write_varint(v.size)?;
+            self.snappy.write_bytes(&v.buffer)?;
+        } else if let Some(v) = any.downcast_ref::<value_structure::Pointer>() {
+            self.snappy.write_type(&(trace::Type::TypeOpaque as u8))?;
+            self.snappy.write_varint(v.value as usize)?;
+        } else {
+            return Err(EncoderError::unsupported_value(CallError::NoDetailsParsed));
+        }
+        Ok(())
+    }
+
+    fn write_enum_sig(&mut self, sig: &crate::signatures::EnumSignature) -> Result<(), EncoderError> {
+        self.snappy.write_varint(sig.id)?;
+        if self.emitted_enums.insert(sig.id) {
+            self.snappy.write_varint(sig.num_values)?;
+            for value in &sig.values {
+                self.snappy.write_string(&value.name)?;
+                self.snappy.write_signed_varint(value.value)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_struct_sig(&mut self, sig: &crate::signatures::StructSignature) -> Result<(), EncoderError> {
+        self.snappy.write_varint(sig.id)?;
+        if self.emitted_structs.insert(sig.id) {
+            self.snappy.write_string(&sig.name)?;
+            self.snappy.write_varint(sig.num_members)?;
+            for name in &sig.member_names {
+                self.snappy.write_string(name)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_bitmask_sig(&mut self, sig: &crate::signatures::BitmaskSignature) -> Result<(), EncoderError> {
+        self.snappy.write_varint(sig.id)?;
+        if self.emitted_bitmasks.insert(sig.id) {
+            self.snappy.write_varint(sig.num_flags)?;
+            for flag in &sig.bitmask_flags {
+                self.snappy.write_string(&flag.name)?;
+                self.snappy.write_varint(flag.value)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_backtrace(&mut self, frames: &[std::rc::Rc<crate::signatures::BacktraceFrame>]) -> Result<(), EncoderError> {
+        self.snappy.write_varint(frames.len())?;
+        for frame in frames {
+            self.snappy.write_varint(frame.id)?;
+            if self.emitted_backtrace_frames.insert(frame.id) {
+                // `read_string` rejects a zero-length string, so an empty
+                // field is left out instead of written empty.
+                for (detail, text) in [
+                    (trace::BacktraceDetail::BacktraceModule, &frame.module),
+                    (trace::BacktraceDetail::BacktraceFunction, &frame.function),
+                    (trace::BacktraceDetail::BacktraceFilename, &frame.filename),
+                ] {
+                    if !text.is_empty() {
+                        self.snappy.write_type(&(detail as u8))?;
+                        self.snappy.write_string(text)?;
+                    }
+                }
+                self.snappy.write_type(&(trace::BacktraceDetail::BacktraceLinenumber as u8))?;
+                self.snappy.write_varint(frame.linenumber)?;
+                self.snappy.write_type(&(trace::BacktraceDetail::BacktraceOffset as u8))?;
+                self.snappy.write_varint(frame.offset)?;
+                // Only a frame's first occurrence carries fields; a cached
+                // frame is its id alone, as `Parser::parse_backtrace_frame` reads it.
+                self.snappy.write_type(&(trace::BacktraceDetail::BacktraceEnd as u8))?;
+            }
+        }
+        Ok(())
+    }
+}